@@ -0,0 +1,227 @@
+//! # Auth
+//! OAuth access tokens for script apps, refreshed before they expire.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use futures::lock::Mutex as AsyncMutex;
+use reqwest::header::USER_AGENT;
+use reqwest::Client;
+use serde::Deserialize;
+
+use crate::config::Config;
+use crate::util::{error::from_body, url, RouxError, Transport};
+
+/// How long before expiry a token gets refreshed.
+const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+#[derive(Deserialize, Debug)]
+struct AuthData {
+    pub access_token: String,
+    pub expires_in: u64,
+}
+
+#[derive(Clone)]
+struct Token {
+    access_token: String,
+    expires_at: Instant,
+}
+
+impl Token {
+    fn expires_soon(&self) -> bool {
+        Instant::now() + REFRESH_MARGIN >= self.expires_at
+    }
+}
+
+/// Credentials and current access token of a logged in user.
+pub(crate) struct Auth {
+    pub config: Config,
+    client: Client,
+    /// Sends token requests, `client` unless replaced in tests.
+    transport: Arc<dyn Transport>,
+    token: Mutex<Token>,
+    /// Held while logging in again, so concurrent requests refresh the token only once.
+    refresh: AsyncMutex<()>,
+}
+
+impl Auth {
+    /// Login as the user in `config`.
+    pub async fn login(config: Config, client: Client) -> Result<Auth, RouxError> {
+        let transport: Arc<dyn Transport> = Arc::new(client.clone());
+        let token = request_token(&config, &client, &*transport).await?;
+
+        Ok(Auth {
+            config,
+            client,
+            transport,
+            token: Mutex::new(token),
+            refresh: AsyncMutex::new(()),
+        })
+    }
+
+    /// The current access token, which may have expired.
+    pub fn access_token(&self) -> String {
+        self.token.lock().unwrap().access_token.to_owned()
+    }
+
+    /// The current access token, logging in again first if it is about to expire.
+    pub async fn ensure_token(&self) -> Result<String, RouxError> {
+        if let Some(access_token) = self.fresh_token() {
+            return Ok(access_token);
+        }
+
+        let _refresh = self.refresh.lock().await;
+
+        // Another request may have logged in again while this one waited for the lock.
+        if let Some(access_token) = self.fresh_token() {
+            return Ok(access_token);
+        }
+
+        let token = request_token(&self.config, &self.client, &*self.transport).await?;
+        *self.token.lock().unwrap() = token.clone();
+
+        Ok(token.access_token)
+    }
+
+    /// The current access token, unless it is about to expire.
+    fn fresh_token(&self) -> Option<String> {
+        let token = self.token.lock().unwrap();
+
+        if token.expires_soon() {
+            None
+        } else {
+            Some(token.access_token.to_owned())
+        }
+    }
+}
+
+/// Script apps don't get refresh tokens, so each token comes from a new password grant.
+async fn request_token(
+    config: &Config,
+    client: &Client,
+    transport: &dyn Transport,
+) -> Result<Token, RouxError> {
+    let url = &url::build_url("api/v1/access_token")[..];
+    let form = [
+        ("grant_type", "password"),
+        ("username", &config.username.to_owned().unwrap()),
+        ("password", &config.password.to_owned().unwrap()),
+    ];
+
+    let request = client
+        .post(url)
+        .header(USER_AGENT, &config.user_agent[..])
+        .basic_auth(&config.client_id, Some(&config.client_secret))
+        .form(&form);
+
+    let response = transport.execute(request.build()?).await?;
+
+    if response.status() == 200 {
+        let auth_data = from_body::<AuthData>(&response.text().await?)?;
+
+        Ok(Token {
+            access_token: auth_data.access_token,
+            expires_at: Instant::now() + Duration::from_secs(auth_data.expires_in),
+        })
+    } else {
+        Err(RouxError::Status(response))
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{AsyncMutex, Auth, Token};
+    use crate::config::Config;
+    use crate::util::transport::tests::MockTransport;
+    use crate::util::RouxError;
+    use futures::future::join_all;
+    use reqwest::Client;
+    use std::sync::{Arc, Mutex};
+    use std::time::{Duration, Instant};
+
+    static USER_AGENT: &str = "macos:roux:v0.3.0 (by /u/beanpup_py)";
+
+    #[test]
+    fn test_expires_soon() {
+        let token = Token {
+            access_token: String::new(),
+            expires_at: Instant::now() + Duration::from_secs(30),
+        };
+        assert!(token.expires_soon());
+
+        let token = Token {
+            access_token: String::new(),
+            expires_at: Instant::now() + Duration::from_secs(3600),
+        };
+        assert!(!token.expires_soon());
+    }
+
+    /// An `Auth` whose token expires at `expires_at`, getting new tokens from `transport`.
+    pub(crate) fn auth(transport: Arc<MockTransport>, expires_at: Instant) -> Auth {
+        let mut config = Config::new(USER_AGENT, "id", "secret");
+        config.username = Some("ferris".to_owned());
+        config.password = Some("hunter2".to_owned());
+
+        Auth {
+            config,
+            client: Client::new(),
+            transport,
+            token: Mutex::new(Token {
+                access_token: "expired".to_owned(),
+                expires_at,
+            }),
+            refresh: AsyncMutex::new(()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_expired_token() {
+        let transport = Arc::new(MockTransport::json(
+            200,
+            r#"{"access_token": "refreshed", "token_type": "bearer", "expires_in": 3600, "scope": "*"}"#,
+        ));
+        let auth = auth(transport.clone(), Instant::now());
+
+        assert_eq!(auth.ensure_token().await.unwrap(), "refreshed");
+        assert_eq!(auth.access_token(), "refreshed");
+
+        // The new token is fresh, so it's reused.
+        assert_eq!(auth.ensure_token().await.unwrap(), "refreshed");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(
+            requests[0].url().as_str(),
+            "https://www.reddit.com/api/v1/access_token/.json"
+        );
+        assert_eq!(requests[0].headers()["user-agent"], USER_AGENT);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_once() {
+        let transport = Arc::new(MockTransport::json(
+            200,
+            r#"{"access_token": "refreshed", "expires_in": 3600}"#,
+        ));
+        let auth = auth(transport.clone(), Instant::now());
+
+        let tokens = join_all((0..4).map(|_| auth.ensure_token())).await;
+
+        assert!(tokens
+            .iter()
+            .all(|token| token.as_deref().ok() == Some("refreshed")));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_failed() {
+        let transport = Arc::new(MockTransport::json(401, r#"{"error": 401}"#));
+        let auth = auth(transport, Instant::now());
+
+        assert!(matches!(
+            auth.ensure_token().await,
+            Err(RouxError::Status(_))
+        ));
+        assert_eq!(auth.access_token(), "expired");
+    }
+}
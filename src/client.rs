@@ -0,0 +1,176 @@
+//! # Client
+//! One HTTP client shared by every subreddit, user and search made from it.
+//!
+//! Each `Subreddit::new` creates its own connection pool, which adds up when watching
+//! many subreddits. A `RedditClient` creates them all on the same pool instead.
+//!
+//! ```no_run
+//! use roux::RedditClient;
+//!
+//! # async fn example() {
+//! let client = RedditClient::new("macos:roux:v0.3.0 (by /u/beanpup_py)");
+//!
+//! let rust = client.subreddit("rust");
+//! let golang = client.subreddit("golang");
+//!
+//! let hot = rust.hot(25, None).await;
+//! let latest = golang.latest(25, None).await;
+//! # }
+//! ```
+
+use futures::stream::{self, StreamExt};
+use reqwest::Client;
+
+use crate::live::LiveThread;
+use crate::search::Search;
+use crate::subreddit::responses::Submissions;
+use crate::subreddit::{Subreddit, SubredditBuilder};
+use crate::subreddits::Subreddits;
+use crate::user::User;
+use crate::util::RouxError;
+
+/// Factory for read-only modules sharing one HTTP client.
+#[derive(Clone)]
+pub struct RedditClient {
+    client: Client,
+    max_retries: u32,
+}
+
+impl RedditClient {
+    /// Create a new `RedditClient` sending a `User-Agent` header,
+    /// see `Subreddit::new_with_user_agent`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `user_agent` can't be sent as a header, e.g. because it contains a
+    /// newline. Use `try_new` to handle that instead.
+    pub fn new(user_agent: &str) -> RedditClient {
+        Self::try_new(user_agent).unwrap()
+    }
+
+    /// Create a new `RedditClient` sending a `User-Agent` header, failing with
+    /// `RouxError::Network` if the HTTP client can't be built.
+    pub fn try_new(user_agent: &str) -> Result<RedditClient, RouxError> {
+        let client = Client::builder().user_agent(user_agent).build()?;
+
+        Ok(Self::new_with_http_client(client))
+    }
+
+    /// Create a new `RedditClient` with a provided HTTP client.
+    pub fn new_with_http_client(http_client: Client) -> RedditClient {
+        RedditClient {
+            client: http_client,
+            max_retries: 0,
+        }
+    }
+
+    /// Retry rate limited requests of the subreddits made from this client,
+    /// see `Subreddit::with_max_retries`.
+    pub fn with_max_retries(mut self, max_retries: u32) -> RedditClient {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Get a subreddit.
+    pub fn subreddit(&self, name: &str) -> Subreddit {
+        SubredditBuilder::new(name)
+            .client(self.client.clone())
+            .max_retries(self.max_retries)
+            .build()
+    }
+
+    /// Get the hot posts of many subreddits, with up to `concurrency` requests at a time.
+    ///
+    /// Results are paired with the subreddit name, in the order the requests finish.
+    /// Keep `concurrency` low enough to stay under the rate limit.
+    ///
+    /// ```no_run
+    /// use roux::RedditClient;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let client = RedditClient::new("macos:roux:v0.3.0 (by /u/beanpup_py)");
+    ///
+    /// for (name, hot) in client.fetch_hot_many(&["rust", "golang"], 25, 4).await {
+    ///     println!("r/{}: {} posts", name, hot.map(|hot| hot.len()).unwrap_or(0));
+    /// }
+    /// # }
+    /// ```
+    pub async fn fetch_hot_many(
+        &self,
+        names: &[&str],
+        limit: u32,
+        concurrency: usize,
+    ) -> Vec<(String, Result<Submissions, RouxError>)> {
+        let requests = names.iter().map(|name| {
+            let subreddit = self.subreddit(name);
+
+            async move {
+                let hot = subreddit.hot(limit, None).await;
+                (subreddit.name, hot)
+            }
+        });
+
+        stream::iter(requests)
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Get a user.
+    pub fn user(&self, name: &str) -> User {
+        User::new_with_http_client(name, self.client.clone()).with_max_retries(self.max_retries)
+    }
+
+    /// Search all of Reddit.
+    pub fn search(&self) -> Search {
+        Search::new_with_http_client(self.client.clone()).with_max_retries(self.max_retries)
+    }
+
+    /// Get a live thread.
+    pub fn live_thread(&self, id: &str) -> LiveThread {
+        LiveThread::new_with_http_client(id, self.client.clone()).with_max_retries(self.max_retries)
+    }
+
+    /// Discover subreddits.
+    pub fn subreddits(&self) -> Subreddits {
+        Subreddits::new_with_http_client(self.client.clone()).with_max_retries(self.max_retries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RedditClient;
+    use crate::util::RouxError;
+    use tokio;
+
+    static USER_AGENT: &str = "macos:roux:v0.3.0 (by /u/beanpup_py)";
+
+    #[test]
+    fn test_try_new() {
+        assert!(RedditClient::try_new(USER_AGENT).is_ok());
+        assert!(matches!(
+            RedditClient::try_new("roux\n(by /u/beanpup_py)"),
+            Err(RouxError::Network(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_shared_client() {
+        let client = RedditClient::new(USER_AGENT);
+
+        for name in &["rust", "golang", "python"] {
+            let subreddit = client.subreddit(name);
+            assert_eq!(subreddit.name, *name);
+            assert!(subreddit.hot(5, None).await.is_ok());
+        }
+
+        assert!(client.user("beneater").about().await.is_ok());
+
+        let hot = client
+            .fetch_hot_many(&["rust", "golang", "python"], 5, 2)
+            .await;
+        assert_eq!(hot.len(), 3);
+        assert!(hot.iter().all(|(_, hot)| hot.is_ok()));
+    }
+}
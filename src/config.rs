@@ -4,7 +4,6 @@ pub struct Config {
     pub client_secret: String,
     pub username: Option<String>,
     pub password: Option<String>,
-    pub access_token: Option<String>,
 }
 
 impl Config {
@@ -15,7 +14,6 @@ impl Config {
             client_secret: client_secret.to_owned(),
             username: None,
             password: None,
-            access_token: None,
         }
     }
 }
@@ -1,4 +1,6 @@
 #![deny(missing_docs)]
+// `RouxError::Status` carries the whole response so callers can inspect it.
+#![allow(clippy::result_large_err)]
 
 //! # roux.rs
 //! This crate provides simple access to the Reddit API.
@@ -52,14 +54,41 @@
 //!
 //! me.submit_link("LINK_TITLE", "LINK", "SUBREDDIT");
 //! ```
+//! ### Read A Subreddit As The User
+//! ```should_fail
+//! use roux::Reddit;
+//!
+//! let client = Reddit::new("USER_AGENT", "CLIENT_ID", "CLIENT_SECRET")
+//!     .username("USERNAME")
+//!     .password("PASSWORD")
+//!     .login()
+//!     .await;
+//!
+//! let me = client.unwrap();
+//!
+//! let hot = me.subreddit("SUBREDDIT").hot(25, None).await;
+//! ```
+//!
+//! ## Features
+//! None of these are enabled by default.
+//!
+//! * `chrono`: timestamps as `chrono::DateTime<Utc>`, see `util::time`.
+//! * `gzip`: ask for gzip compressed responses and decompress them.
+//! * `brotli`: ask for brotli compressed responses and decompress them.
+//!
+//! Compression applies to the HTTP clients roux creates, and to provided ones built with
+//! the same reqwest features. Listings compress well, so this saves a lot of bandwidth
+//! when polling many of them.
 
-use serde::Deserialize;
-
-use reqwest::header::USER_AGENT;
 use reqwest::Client;
 
+mod auth;
 mod config;
 
+/// Client module.
+pub mod client;
+pub use client::RedditClient;
+
 /// Subreddit module.
 pub mod subreddit;
 pub use subreddit::Subreddit;
@@ -72,11 +101,22 @@ pub use user::User;
 pub mod me;
 pub use me::Me;
 
+/// Search module.
+pub mod search;
+pub use search::Search;
+
+/// Subreddits module.
+pub mod subreddits;
+pub use subreddits::Subreddits;
+
+/// Live thread module.
+pub mod live;
+pub use live::LiveThread;
+
 pub mod responses;
 
 /// Utils for requests.
 pub mod util;
-use util::url;
 
 /// Client to use OAuth with Reddit.
 pub struct Reddit {
@@ -84,11 +124,6 @@ pub struct Reddit {
     client: Client,
 }
 
-#[derive(Deserialize, Debug)]
-struct AuthData {
-    pub access_token: String,
-}
-
 impl Reddit {
     /// Creates a `Reddit` instance with user_agent, client_id, and client_secret.
     pub fn new(user_agent: &str, client_id: &str, client_secret: &str) -> Reddit {
@@ -96,9 +131,14 @@ impl Reddit {
     }
 
     /// Creates a `Reddit` instance with user_agent, client_id, and client_secret using a provided HTTP client.
-    pub fn new_with_http_client(user_agent: &str, client_id: &str, client_secret: &str, http_client: Client) -> Reddit {
+    pub fn new_with_http_client(
+        user_agent: &str,
+        client_id: &str,
+        client_secret: &str,
+        http_client: Client,
+    ) -> Reddit {
         Reddit {
-            config: config::Config::new(&user_agent, &client_id, &client_secret),
+            config: config::Config::new(user_agent, client_id, client_secret),
             client: http_client,
         }
     }
@@ -117,27 +157,8 @@ impl Reddit {
 
     /// Login as a user.
     pub async fn login(self) -> Result<me::Me, util::RouxError> {
-        let url = &url::build_url("api/v1/access_token")[..];
-        let form = [
-            ("grant_type", "password"),
-            ("username", &self.config.username.to_owned().unwrap()),
-            ("password", &self.config.password.to_owned().unwrap()),
-        ];
-
-        let request = self
-            .client
-            .post(url)
-            .header(USER_AGENT, &self.config.user_agent[..])
-            .basic_auth(&self.config.client_id, Some(&self.config.client_secret))
-            .form(&form);
-
-        let response = request.send().await?;
-
-        if response.status() == 200 {
-            let auth_data = response.json::<AuthData>().await.unwrap();
-            Ok(me::Me::new(&auth_data.access_token, self.config))
-        } else {
-            Err(util::RouxError::Status(response))
-        }
+        let auth = auth::Auth::login(self.config, self.client).await?;
+
+        Ok(me::Me::new(auth))
     }
 }
@@ -0,0 +1,143 @@
+//! # Live Threads
+//! A read-only module to read Reddit live threads, which post a running series of updates
+//! about an event instead of comments.
+//!
+//! # Usage
+//! ```rust
+//! use roux::LiveThread;
+//! use tokio;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let thread = LiveThread::new("ta535s1hq2je");
+//!     // Now you are able to:
+//!
+//!     // Get the title, description and state of the thread.
+//!     let about = thread.about().await;
+//!
+//!     // Get the latest 25 updates, newest first.
+//!     let updates = thread.updates(25, None).await;
+//! }
+//! ```
+
+extern crate reqwest;
+extern crate serde_json;
+
+pub mod responses;
+
+use crate::util::request::Sender;
+use crate::util::{FeedOption, RateLimit, RouxError};
+use reqwest::Client;
+use responses::{LiveThreadAbout, LiveUpdates};
+
+/// LiveThread.
+pub struct LiveThread {
+    /// The thread ID, as in `/live/{id}`.
+    pub id: String,
+    sender: Sender,
+}
+
+impl LiveThread {
+    /// Create a new `LiveThread` instance.
+    pub fn new(id: &str) -> LiveThread {
+        Self::new_with_http_client(id, Client::new())
+    }
+
+    /// Create a new `LiveThread` instance with a provided HTTP client.
+    pub fn new_with_http_client(id: &str, http_client: Client) -> LiveThread {
+        LiveThread {
+            id: id.to_owned(),
+            sender: Sender::new(http_client),
+        }
+    }
+
+    /// Retry rate limited requests, see `Subreddit::with_max_retries`.
+    pub fn with_max_retries(mut self, max_retries: u32) -> LiveThread {
+        self.sender.max_retries = max_retries;
+        self
+    }
+
+    /// Rate limit state reported with the last response, see `Subreddit::last_rate_limit`.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.sender.last_rate_limit()
+    }
+
+    /// Get the title, description and state of the thread.
+    pub async fn about(&self) -> Result<LiveThreadAbout, RouxError> {
+        let url = format!("https://www.reddit.com/live/{}/about.json", self.id);
+
+        self.sender.get(&url, &[]).await
+    }
+
+    /// Get updates to the thread, newest first.
+    ///
+    /// Page through older updates with `FeedOption::after` set to the `name` of the last
+    /// update, or the `after` of the listing.
+    pub async fn updates(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<LiveUpdates, RouxError> {
+        let url = format!("https://www.reddit.com/live/{}.json", self.id);
+        let mut params = vec![("limit", limit.to_string())];
+
+        if let Some(options) = options {
+            params.extend(options.params());
+        }
+
+        self.sender.get(&url, &params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LiveThread;
+    use crate::util::transport::tests::MockTransport;
+    use crate::util::FeedOption;
+    use std::sync::Arc;
+    use tokio;
+
+    #[tokio::test]
+    async fn test_updates_request() {
+        let transport = Arc::new(MockTransport::json(
+            200,
+            r#"{"kind": "Listing", "data": {"children": []}}"#,
+        ));
+        let mut thread = LiveThread::new("ta535s1hq2je");
+        thread.sender.transport = transport.clone();
+
+        assert!(thread.updates(10, None).await.unwrap().is_empty());
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].url().path(), "/live/ta535s1hq2je.json");
+        assert!(requests[0].url().query().unwrap().contains("limit=10"));
+    }
+
+    #[tokio::test]
+    async fn test_no_auth() {
+        let thread = LiveThread::new("ta535s1hq2je");
+
+        // Test about
+        let about = thread.about().await;
+        assert!(about.is_ok());
+        assert_eq!(about.unwrap().data.id, "ta535s1hq2je");
+
+        // Test updates
+        let updates = thread.updates(5, None).await;
+        assert!(updates.is_ok());
+
+        let updates = updates.unwrap();
+        assert_eq!(updates.len(), 5);
+
+        // Test paging
+        let after = updates.data.after.unwrap();
+        let next = thread
+            .updates(5, Some(FeedOption::new().after(&after)))
+            .await
+            .unwrap();
+        assert_ne!(
+            next.data.children[0].data.id,
+            updates.data.children[0].data.id
+        );
+    }
+}
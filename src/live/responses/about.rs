@@ -0,0 +1,82 @@
+//! # Live Thread About Responses
+use crate::responses::BasicThing;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// LiveThreadAboutData
+#[derive(Debug, Deserialize)]
+pub struct LiveThreadAboutData {
+    /// The thread ID, as in `/live/{id}`.
+    pub id: String,
+    /// The thread title.
+    pub title: String,
+    /// The description in **Markdown** format.
+    #[serde(default)]
+    pub description: String,
+    /// The description rendered as HTML.
+    pub description_html: Option<String>,
+    /// The resources sidebar in **Markdown** format.
+    #[serde(default)]
+    pub resources: String,
+    /// The resources sidebar rendered as HTML.
+    pub resources_html: Option<String>,
+    /// `"live"` while updates are being posted, `"complete"` once the thread is closed.
+    pub state: String,
+    /// The number of people watching the thread, `None` once it's complete.
+    pub viewer_count: Option<u64>,
+    /// The total number of views, which Reddit only counts for some threads.
+    pub total_views: Option<u64>,
+    /// The websocket that pushes new updates, `None` once the thread is complete.
+    pub websocket_url: Option<String>,
+    /// This is `true` if the thread is marked NSFW.
+    pub nsfw: bool,
+    /// Created
+    pub created: f64,
+    /// Created utc
+    pub created_utc: f64,
+}
+
+impl LiveThreadAboutData {
+    /// This is `true` while updates are still being posted.
+    pub fn is_live(&self) -> bool {
+        self.state == "live"
+    }
+
+    /// `created_utc` as a `DateTime<Utc>`.
+    #[cfg(feature = "chrono")]
+    pub fn created_utc_datetime(&self) -> Option<DateTime<Utc>> {
+        crate::util::time::from_timestamp(self.created_utc)
+    }
+}
+
+/// LiveThreadAbout
+pub type LiveThreadAbout = BasicThing<LiveThreadAboutData>;
+
+#[cfg(test)]
+mod tests {
+    use super::LiveThreadAbout;
+    use serde_json::json;
+
+    #[test]
+    fn test_about() {
+        let about: LiveThreadAbout = serde_json::from_value(json!({
+            "kind": "LiveUpdateEvent",
+            "data": {
+                "id": "ta535s1hq2je", "title": "Launch", "description": "Liftoff *soon*",
+                "description_html": "<div class=\"md\"><p>Liftoff <em>soon</em></p></div>",
+                "resources": "", "resources_html": "", "state": "complete",
+                "viewer_count": null, "viewer_count_fuzzed": null, "total_views": 1200,
+                "websocket_url": null, "nsfw": false, "announcement_url": null,
+                "created": 1600000000.0, "created_utc": 1600000000.0
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(about.kind, "LiveUpdateEvent");
+        assert_eq!(about.data.title, "Launch");
+        assert!(!about.data.is_live());
+        assert_eq!(about.data.viewer_count, None);
+        assert_eq!(about.data.total_views, Some(1200));
+    }
+}
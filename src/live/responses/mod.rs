@@ -0,0 +1,6 @@
+//! # Live Thread Responses
+mod about;
+mod updates;
+
+pub use about::{LiveThreadAbout, LiveThreadAboutData};
+pub use updates::{LiveUpdates, LiveUpdatesData};
@@ -0,0 +1,75 @@
+//! # Live Thread Update Responses
+use crate::responses::BasicListing;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// LiveUpdatesData
+#[derive(Debug, Deserialize)]
+pub struct LiveUpdatesData {
+    /// The update ID, a UUID.
+    pub id: String,
+    /// The update ID prefixed with `LiveUpdate_`, used as the `after` or `before` cursor.
+    pub name: String,
+    /// The update text in **Markdown** format.
+    pub body: String,
+    /// The update text rendered as HTML.
+    pub body_html: Option<String>,
+    /// The author, `None` if the account was deleted.
+    pub author: Option<String>,
+    /// This is `true` if the update was struck through as incorrect.
+    pub stricken: bool,
+    /// Created
+    pub created: f64,
+    /// Created utc
+    pub created_utc: f64,
+}
+
+impl LiveUpdatesData {
+    /// `created_utc` as a `DateTime<Utc>`.
+    #[cfg(feature = "chrono")]
+    pub fn created_utc_datetime(&self) -> Option<DateTime<Utc>> {
+        crate::util::time::from_timestamp(self.created_utc)
+    }
+}
+
+/// LiveUpdates
+pub type LiveUpdates = BasicListing<LiveUpdatesData>;
+
+#[cfg(test)]
+mod tests {
+    use super::LiveUpdates;
+    use serde_json::json;
+
+    #[test]
+    fn test_updates() {
+        let updates: LiveUpdates = serde_json::from_value(json!({
+            "kind": "Listing",
+            "data": {
+                "after": "LiveUpdate_b", "before": null, "dist": null, "modhash": null,
+                "children": [
+                    {"kind": "LiveUpdate", "data": {
+                        "id": "a", "name": "LiveUpdate_a", "body": "Liftoff!",
+                        "body_html": "<div class=\"md\"><p>Liftoff!</p></div>",
+                        "author": "ferris", "stricken": false, "embeds": [],
+                        "mobile_embeds": [], "created": 1600000100.0,
+                        "created_utc": 1600000100.0
+                    }},
+                    {"kind": "LiveUpdate", "data": {
+                        "id": "b", "name": "LiveUpdate_b", "body": "T-10",
+                        "body_html": null, "author": null, "stricken": true, "embeds": [],
+                        "mobile_embeds": [], "created": 1600000000.0,
+                        "created_utc": 1600000000.0
+                    }}
+                ]
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates.data.after.as_deref(), Some("LiveUpdate_b"));
+        assert_eq!(updates.data.children[0].data.body, "Liftoff!");
+        assert!(updates.data.children[1].data.stricken);
+        assert_eq!(updates.data.children[1].data.author, None);
+    }
+}
@@ -4,72 +4,132 @@
 extern crate reqwest;
 extern crate serde_json;
 
-use reqwest::{header, Client, Response};
+use std::sync::Arc;
+
+use reqwest::{header, Client, Response, StatusCode};
+use serde::de::{DeserializeOwned, IgnoredAny};
 use serde::Serialize;
 
-use crate::config::Config;
-use crate::util::{url, RouxError};
+use crate::auth::Auth;
+use crate::responses::{ApiResponse, Item, Items};
+use crate::util::request::Sender;
+use crate::util::{url, DistinguishKind, RateLimit, RouxError, VoteDirection};
 
 pub mod responses;
-use crate::subreddit::responses::Submissions;
-use responses::{Inbox, MeData};
+use crate::subreddit::responses::SubredditCommentsData;
+use crate::subreddit::Subreddit;
+use crate::user::User;
+use responses::{CommentedData, Inbox, MeData, UserTextData};
 
 /// Me
 pub struct Me {
-    /// Access token
-    pub access_token: String,
-    client: Client,
-    config: Config,
+    sender: Sender,
+    auth: Arc<Auth>,
 }
 
 impl Me {
     /// Create a new `me`
-    pub fn new(access_token: &str, config: Config) -> Me {
+    pub(crate) fn new(auth: Auth) -> Me {
         let mut headers = header::HeaderMap::new();
 
-        headers.insert(
-            header::AUTHORIZATION,
-            header::HeaderValue::from_str(&format!("Bearer {}", access_token)).unwrap(),
-        );
-
         headers.insert(
             header::USER_AGENT,
-            header::HeaderValue::from_str(&config.user_agent[..]).unwrap(),
+            header::HeaderValue::from_str(&auth.config.user_agent[..]).unwrap(),
         );
 
         let client = Client::builder().default_headers(headers).build().unwrap();
 
         Me {
-            access_token: access_token.to_owned(),
-            config,
-            client,
+            sender: Sender::new(client),
+            auth: Arc::new(auth),
         }
     }
 
-    async fn get(&self, url: &str) -> Result<Response, RouxError> {
-        let get_url = url::build_oauth(url);
+    /// Retry rate limited requests, see `Subreddit::with_max_retries`. Subreddits and users
+    /// got from `Me` retry as often.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Me {
+        self.sender.max_retries = max_retries;
+        self
+    }
 
-        match self.client.get(&get_url[..]).send().await {
-            Ok(response) => Ok(response),
-            Err(e) => Err(e.into()),
-        }
+    /// Rate limit state reported with the last response, see `Subreddit::last_rate_limit`.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.sender.last_rate_limit()
+    }
+
+    /// Access token. Tokens expire after an hour and are refreshed automatically
+    /// before each request, so this may change over time.
+    pub fn access_token(&self) -> String {
+        self.auth.access_token()
+    }
+
+    /// Login again if the access token expires within the next minute.
+    /// This is done before every request, so calling it is rarely needed.
+    pub async fn ensure_token(&self) -> Result<(), RouxError> {
+        self.auth.ensure_token().await?;
+        Ok(())
     }
 
+    /// Get `url` as the logged in user, deserializing the response.
+    async fn get_json<T: DeserializeOwned>(&self, url: &str) -> Result<T, RouxError> {
+        let request = self.sender.client.get(&url::build_oauth(url));
+        self.sender.send(request, Some(&self.auth)).await
+    }
+
+    /// Post a form to `url` as the logged in user, returning the response whatever its status.
     async fn post<T: Serialize>(&self, url: &str, form: T) -> Result<Response, RouxError> {
-        let post_url = url::build_oauth(url).to_owned();
+        let request = self.sender.client.post(&url::build_oauth(url)).form(&form);
+        self.sender.send_raw(request, Some(&self.auth)).await
+    }
+
+    /// Post a form to an endpoint that responds with nothing of interest on success.
+    async fn post_action<T: Serialize>(&self, url: &str, form: T) -> Result<(), RouxError> {
+        let response = self.post(url, form).await?;
 
-        match self.client.post(&post_url[..]).form(&form).send().await {
-            Ok(response) => Ok(response),
-            Err(e) => Err(e.into()),
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(RouxError::Status(response))
         }
     }
 
+    /// Post a form for a moderator action, which Reddit rejects with a `403` status if the
+    /// user doesn't moderate the subreddit or lacks the permission.
+    async fn post_mod_action<T: Serialize>(&self, url: &str, form: T) -> Result<(), RouxError> {
+        match self.post_action(url, form).await {
+            Err(RouxError::Status(response)) if response.status() == StatusCode::FORBIDDEN => {
+                Err(RouxError::Forbidden)
+            }
+            result => result,
+        }
+    }
+
+    /// Post a form with `api_type=json`, deserializing the response.
+    async fn post_api<T: DeserializeOwned, F: Serialize>(
+        &self,
+        url: &str,
+        form: F,
+    ) -> Result<ApiResponse<T>, RouxError> {
+        let request = self.sender.client.post(&url::build_oauth(url)).form(&form);
+        self.sender.send(request, Some(&self.auth)).await
+    }
+
     /// Get me
     pub async fn me(&self) -> Result<MeData, RouxError> {
-        match self.get("api/v1/me").await {
-            Ok(res) => Ok(res.json::<MeData>().await?),
-            Err(e) => Err(e.into()),
-        }
+        self.get_json("api/v1/me").await
+    }
+
+    /// Get a subreddit, making its requests as the logged in user.
+    pub fn subreddit(&self, name: &str) -> Subreddit {
+        Subreddit::new_oauth(name, self.sender.client.clone(), self.auth.clone())
+            .with_max_retries(self.sender.max_retries)
+    }
+
+    /// Get a user, making its requests as the logged in user. This is needed for the
+    /// logged in user's own private listings, like `User::saved`.
+    pub fn user(&self, name: &str) -> User {
+        User::new_oauth(name, self.sender.client.clone(), self.auth.clone())
+            .with_max_retries(self.sender.max_retries)
     }
 
     /// Submit link
@@ -123,83 +183,280 @@ impl Me {
         self.post("api/compose", &form).await
     }
 
-    /// Get user's submitted posts.
-    pub async fn inbox(&self) -> Result<Inbox, RouxError> {
-        Ok(self.get("message/inbox").await?.json::<Inbox>().await?)
+    /// Send a private message to the user `to`.
+    ///
+    /// Messages to users who blocked the logged in user are rejected with
+    /// `ApiErrorKind::UserBlocked`, and sending too many with `ApiErrorKind::RateLimit`.
+    pub async fn send_message(&self, to: &str, subject: &str, body: &str) -> Result<(), RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("to", to),
+            ("subject", subject),
+            ("text", body),
+        ];
+
+        self.post_api::<IgnoredAny, _>("api/compose", &form)
+            .await?
+            .check()
     }
 
-    /// Get saved
-    pub async fn saved(&self) -> Result<Submissions, RouxError> {
-        let url = format!(
-            "user/{}/saved/.json",
-            self.config.username.to_owned().unwrap()
-        );
+    /// Block the user `username` across Reddit, hiding their content and messages from the
+    /// logged in user.
+    ///
+    /// Fails with `ApiErrorKind::UserNotFound` if there is no user with that name.
+    pub async fn block_user(&self, username: &str) -> Result<(), RouxError> {
+        let form = [("api_type", "json"), ("name", username)];
 
-        Ok(self.get(&url).await?.json::<Submissions>().await?)
+        self.post_api::<IgnoredAny, _>("api/block_user", &form)
+            .await?
+            .check()
     }
 
-    /// Get upvoted
-    pub async fn upvoted(&self) -> Result<Submissions, RouxError> {
-        let url = format!(
-            "user/{}/upvoted/.json",
-            self.config.username.to_owned().unwrap()
-        );
+    /// Get the messages and comment replies in the user's inbox, newest first.
+    pub async fn inbox(&self) -> Result<Inbox, RouxError> {
+        self.get_json("message/inbox").await
+    }
 
-        Ok(self.get(&url).await?.json::<Submissions>().await?)
+    /// Get the posts and comments the user saved, see `User::saved`.
+    pub async fn saved(&self) -> Result<Items, RouxError> {
+        self.own_user().saved().await
     }
 
-    /// Get downvoted
-    pub async fn downvoted(&self) -> Result<Submissions, RouxError> {
-        let url = format!(
-            "user/{}/downvoted/.json",
-            self.config.username.to_owned().unwrap()
-        );
+    /// Get the posts and comments the user upvoted, see `User::upvoted`.
+    pub async fn upvoted(&self) -> Result<Items, RouxError> {
+        self.own_user().upvoted().await
+    }
+
+    /// Get the posts and comments the user downvoted, see `User::downvoted`.
+    pub async fn downvoted(&self) -> Result<Items, RouxError> {
+        self.own_user().downvoted().await
+    }
 
-        Ok(self.get(&url).await?.json::<Submissions>().await?)
+    /// The logged in user. Logins always have a username, it's needed for the password grant.
+    fn own_user(&self) -> User {
+        self.user(self.auth.config.username.as_deref().unwrap_or_default())
     }
 
-    /// Get users unread messages
+    /// Get the user's unread messages and comment replies, newest first.
     pub async fn unread(&self) -> Result<Inbox, RouxError> {
-        Ok(self.get("message/unread").await?.json::<Inbox>().await?)
+        self.get_json("message/unread").await
     }
 
-    /// Mark messages as read
-    pub async fn mark_read(&self, ids: &str) -> Result<Response, RouxError> {
+    /// Mark messages as read, by their fullnames separated by commas.
+    pub async fn mark_read(&self, ids: &str) -> Result<(), RouxError> {
         let form = [("id", ids)];
-        self.post("api/read_message", &form).await
+        self.post_action("api/read_message", &form).await
+    }
+
+    /// Mark every message in the inbox as read.
+    pub async fn mark_all_read(&self) -> Result<(), RouxError> {
+        let form: [(&str, &str); 0] = [];
+        self.post_action("api/read_all_messages", &form).await
     }
 
-    /// Mark messages as unread
-    pub async fn mark_unread(&self, ids: &str) -> Result<Response, RouxError> {
+    /// Mark messages as unread, by their fullnames separated by commas.
+    pub async fn mark_unread(&self, ids: &str) -> Result<(), RouxError> {
         let form = [("id", ids)];
-        self.post("api/unread_message", &form).await
+        self.post_action("api/unread_message", &form).await
     }
 
-    /// Comment
+    /// Vote on a post or comment by its fullname, e.g. `t3_abc`.
+    pub async fn vote(&self, fullname: &str, direction: VoteDirection) -> Result<(), RouxError> {
+        let form = [("id", fullname.to_owned()), ("dir", direction.to_string())];
+        self.post_action("api/vote", &form).await
+    }
+
+    /// Reply to a post or comment by its fullname, e.g. `t3_abc`, returning the new comment.
+    ///
+    /// Commenting too often is rejected with `ApiErrorKind::RateLimit`, see
+    /// `ApiError::retry_after` for how long to wait.
+    pub async fn reply(
+        &self,
+        parent_fullname: &str,
+        body: &str,
+    ) -> Result<SubredditCommentsData, RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("thing_id", parent_fullname),
+            ("text", body),
+        ];
+
+        let mut commented = self
+            .post_api::<CommentedData, _>("api/comment", &form)
+            .await?
+            .into_result()?;
+
+        match commented.things.pop() {
+            Some(comment) => Ok(comment.data),
+            None => Err(RouxError::Parse(serde::de::Error::invalid_length(
+                0,
+                &"the new comment",
+            ))),
+        }
+    }
+
+    /// Comment on `parent` with `text`, returning the raw response.
+    #[deprecated(note = "use Me::reply")]
     pub async fn comment(&self, text: &str, parent: &str) -> Result<Response, RouxError> {
         let form = [("text", text), ("parent", parent)];
         self.post("api/comment", &form).await
     }
 
+    /// Save a post or comment by its fullname, optionally in a `category`.
+    /// Categories are only available to Reddit Premium users.
+    pub async fn save(&self, fullname: &str, category: Option<&str>) -> Result<(), RouxError> {
+        let mut form = vec![("id", fullname)];
+
+        if let Some(category) = category {
+            form.push(("category", category));
+        }
+
+        self.post_action("api/save", &form).await
+    }
+
+    /// Unsave a post or comment by its fullname.
+    pub async fn unsave(&self, fullname: &str) -> Result<(), RouxError> {
+        let form = [("id", fullname)];
+        self.post_action("api/unsave", &form).await
+    }
+
+    /// Hide posts by their fullnames, so they no longer show up in the user's feeds.
+    pub async fn hide(&self, fullnames: &[&str]) -> Result<(), RouxError> {
+        let form = [("id", fullnames.join(","))];
+        self.post_action("api/hide", &form).await
+    }
+
+    /// Unhide posts by their fullnames.
+    pub async fn unhide(&self, fullnames: &[&str]) -> Result<(), RouxError> {
+        let form = [("id", fullnames.join(","))];
+        self.post_action("api/unhide", &form).await
+    }
+
+    /// Report a post or comment by its fullname to the subreddit's moderators.
+    ///
+    /// Reasons Reddit rejects, e.g. longer than 100 characters, fail with `RouxError::Api`.
+    pub async fn report(&self, fullname: &str, reason: &str) -> Result<(), RouxError> {
+        if reason.trim().is_empty() {
+            return Err(RouxError::InvalidArgument(
+                "report reason must not be empty".to_owned(),
+            ));
+        }
+
+        let form = [
+            ("api_type", "json"),
+            ("thing_id", fullname),
+            ("reason", reason),
+        ];
+
+        self.post_api::<IgnoredAny, _>("api/report", &form)
+            .await?
+            .check()
+    }
+
     /// Edit
+    #[deprecated(note = "use Me::edit_text, which takes the fullname first")]
     pub async fn edit(&self, text: &str, parent: &str) -> Result<Response, RouxError> {
         let form = [("text", text), ("thing_id", parent)];
         self.post("api/editusertext", &form).await
     }
 
+    /// Replace the text of one of the user's own posts or comments by its fullname,
+    /// returning the edited item.
+    ///
+    /// Editing another user's content fails with `ApiErrorKind::NotAuthor`.
+    pub async fn edit_text(&self, fullname: &str, new_text: &str) -> Result<Item, RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("thing_id", fullname),
+            ("text", new_text),
+        ];
+
+        let mut edited = self
+            .post_api::<UserTextData, _>("api/editusertext", &form)
+            .await?
+            .into_result()?;
+
+        edited.things.pop().ok_or_else(|| {
+            RouxError::Parse(serde::de::Error::invalid_length(0, &"the edited item"))
+        })
+    }
+
+    /// Delete one of the user's own posts or comments by its fullname.
+    pub async fn delete(&self, fullname: &str) -> Result<(), RouxError> {
+        let form = [("id", fullname)];
+        self.post_action("api/del", &form).await
+    }
+
+    /// Lock a post or comment by its fullname, so no one but moderators can comment on it.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate its subreddit.
+    pub async fn lock(&self, fullname: &str) -> Result<(), RouxError> {
+        let form = [("id", fullname)];
+        self.post_mod_action("api/lock", &form).await
+    }
+
+    /// Unlock a post or comment by its fullname.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate its subreddit.
+    pub async fn unlock(&self, fullname: &str) -> Result<(), RouxError> {
+        let form = [("id", fullname)];
+        self.post_mod_action("api/unlock", &form).await
+    }
+
+    /// Approve a post or comment by its fullname, clearing its reports or undoing a removal.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate its subreddit.
+    pub async fn approve(&self, fullname: &str) -> Result<(), RouxError> {
+        let form = [("id", fullname)];
+        self.post_mod_action("api/approve", &form).await
+    }
+
+    /// Remove a post or comment by its fullname. With `spam` it's removed as spam, which
+    /// also trains the subreddit's spam filter.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate its subreddit.
+    pub async fn remove(&self, fullname: &str, spam: bool) -> Result<(), RouxError> {
+        let form = [
+            ("id", fullname),
+            ("spam", if spam { "true" } else { "false" }),
+        ];
+        self.post_mod_action("api/remove", &form).await
+    }
+
+    /// Distinguish one of the user's posts or comments by its fullname. With `sticky`, a
+    /// top level comment is also pinned above the others.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate its subreddit.
+    pub async fn distinguish(
+        &self,
+        fullname: &str,
+        how: DistinguishKind,
+        sticky: bool,
+    ) -> Result<(), RouxError> {
+        let form = [
+            ("id", fullname.to_owned()),
+            ("how", how.to_string()),
+            ("sticky", sticky.to_string()),
+        ];
+        self.post_mod_action("api/distinguish", &form).await
+    }
+
     /// Logout
     pub async fn logout(self) -> Result<(), RouxError> {
         let url = "https://www.reddit.com/api/v1/revoke_token";
 
-        let form = [("access_token", self.access_token.to_owned())];
+        let form = [("access_token", self.auth.access_token())];
 
-        let response = self
+        let request = self
+            .sender
             .client
             .post(url)
-            .basic_auth(&self.config.client_id, Some(&self.config.client_secret))
-            .form(&form)
-            .send()
-            .await?;
+            .basic_auth(
+                &self.auth.config.client_id,
+                Some(&self.auth.config.client_secret),
+            )
+            .form(&form);
+        let response = self.sender.send_raw(request, None).await?;
 
         if response.status() == 204 {
             Ok(())
@@ -208,3 +465,49 @@ impl Me {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Me;
+    use crate::auth::tests::auth;
+    use crate::util::transport::tests::{response, MockTransport};
+    use crate::util::RouxError;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use tokio;
+
+    /// A logged in `Me` sending its requests to `transport`.
+    fn me(transport: Arc<MockTransport>) -> Me {
+        let expires_at = Instant::now() + Duration::from_secs(3600);
+        let mut me = Me::new(auth(Arc::new(MockTransport::json(500, "")), expires_at));
+        me.sender.transport = transport;
+        me
+    }
+
+    #[tokio::test]
+    async fn test_sender() {
+        let mut limited = response(429, "");
+        limited
+            .headers_mut()
+            .insert("retry-after", "0".parse().unwrap());
+        let empty = response(200, r#"{"kind": "Listing", "data": {"children": []}}"#);
+
+        let transport = Arc::new(MockTransport::new(vec![limited, empty]));
+        let me = me(transport.clone()).with_max_retries(1);
+
+        assert!(me.inbox().await.unwrap().data.children.is_empty());
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].url().path(), "/message/inbox/.json");
+        assert_eq!(requests[0].url().query(), Some("raw_json=1"));
+        assert_eq!(requests[0].headers()["authorization"], "Bearer expired");
+    }
+
+    #[tokio::test]
+    async fn test_me_status() {
+        let me = me(Arc::new(MockTransport::json(401, r#"{"error": 401}"#)));
+
+        assert!(matches!(me.me().await, Err(RouxError::Status(_))));
+    }
+}
@@ -0,0 +1,24 @@
+//! # Me Comment Responses
+use crate::responses::{ApiResponse, BasicThing, Item};
+use crate::subreddit::responses::SubredditCommentsData;
+use serde::Deserialize;
+
+/// CommentedData
+#[derive(Debug, Deserialize)]
+pub struct CommentedData {
+    /// The new comment, as the only thing.
+    pub things: Vec<BasicThing<SubredditCommentsData>>,
+}
+
+/// Commented
+pub type Commented = ApiResponse<CommentedData>;
+
+/// UserTextData
+#[derive(Debug, Deserialize)]
+pub struct UserTextData {
+    /// The edited post or comment, as the only thing.
+    pub things: Vec<Item>,
+}
+
+/// UserText
+pub type UserText = ApiResponse<UserTextData>;
@@ -38,5 +38,9 @@ pub struct InboxData {
     pub context: String,
 }
 
+/// Message
+/// A private message or comment reply in the inbox.
+pub type Message = InboxData;
+
 /// Inbox
 pub type Inbox = BasicListing<InboxData>;
@@ -3,4 +3,7 @@ pub mod me;
 pub use me::MeData;
 
 pub mod inbox;
-pub use inbox::{Inbox, InboxData};
+pub use inbox::{Inbox, InboxData, Message};
+
+pub mod comment;
+pub use comment::{Commented, CommentedData, UserText, UserTextData};
@@ -0,0 +1,87 @@
+//! # Item Responses
+use serde::Deserialize;
+
+use super::{BasicThing, Listing};
+use crate::subreddit::responses::{SubmissionsData, SubredditCommentsData};
+
+/// A post or a comment, in listings that mix them.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum Item {
+    /// A post (`t3`).
+    #[serde(rename = "t3")]
+    Post(Box<SubmissionsData>),
+    /// A comment (`t1`).
+    #[serde(rename = "t1")]
+    Comment(Box<SubredditCommentsData>),
+}
+
+impl Item {
+    /// The fullname of the post or comment, e.g. `t3_abc`.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Item::Post(post) => Some(&post.name),
+            Item::Comment(comment) => comment.name.as_deref(),
+        }
+    }
+
+    /// The subreddit the post or comment is in (not including `/r/`).
+    pub fn subreddit(&self) -> Option<&str> {
+        match self {
+            Item::Post(post) => Some(&post.subreddit),
+            Item::Comment(comment) => comment.subreddit.as_deref(),
+        }
+    }
+
+    /// The post, if this is one.
+    pub fn as_post(&self) -> Option<&SubmissionsData> {
+        match self {
+            Item::Post(post) => Some(post),
+            Item::Comment(_) => None,
+        }
+    }
+
+    /// The comment, if this is one.
+    pub fn as_comment(&self) -> Option<&SubredditCommentsData> {
+        match self {
+            Item::Post(_) => None,
+            Item::Comment(comment) => Some(comment),
+        }
+    }
+}
+
+/// Items
+/// A listing of posts and comments, e.g. the ones a user saved.
+pub type Items = BasicThing<Listing<Item>>;
+
+#[cfg(test)]
+mod tests {
+    use super::{Item, Items};
+    use crate::subreddit::responses::submissions::tests::submission_json;
+    use serde_json::json;
+
+    #[test]
+    fn test_items() {
+        let items = json!({"kind": "Listing", "data": {"after": "t1_def", "before": null,
+        "children": [
+            {"kind": "t3", "data": submission_json(json!({}))},
+            {"kind": "t1", "data": {"id": "def", "name": "t1_def", "subreddit": "rust",
+                "body": "comment", "link_id": "t3_abc", "replies": ""}}
+        ]}});
+        let items: Items = serde_json::from_value(items).unwrap();
+        let children = &items.data.children;
+
+        assert!(matches!(children[0], Item::Post(_)));
+        assert_eq!(children[0].name(), Some("t3_abc"));
+        assert_eq!(children[0].as_post().unwrap().title, "title");
+        assert!(children[0].as_comment().is_none());
+
+        assert!(matches!(children[1], Item::Comment(_)));
+        assert_eq!(children[1].name(), Some("t1_def"));
+        assert_eq!(children[1].subreddit(), Some("rust"));
+        assert_eq!(
+            children[1].as_comment().unwrap().body.as_deref(),
+            Some("comment")
+        );
+    }
+}
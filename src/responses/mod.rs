@@ -1,7 +1,15 @@
 //! # Responses
 //! Base responses
 
-use serde::Deserialize;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use serde::{de, Deserialize, Deserializer};
+
+use crate::util::error::{ApiError, ApiErrorKind};
+use crate::util::RouxError;
+
+mod item;
+pub use item::{Item, Items};
 
 /// Basic structure of a Reddit response.
 /// See: https://github.com/reddit-archive/reddit/wiki/JSON
@@ -19,7 +27,7 @@ pub struct BasicThing<T> {
 pub struct Listing<T> {
     /// Modhash
     pub modhash: Option<String>,
-    /// I dunno what this is
+    /// The number of items in this listing.
     pub dist: Option<i32>,
     /// The fullname of the listing that follows after this page.
     pub after: Option<String>,
@@ -29,5 +37,166 @@ pub struct Listing<T> {
     pub children: Vec<T>,
 }
 
+impl<T> Listing<T> {
+    /// The number of items on this page of the listing.
+    pub fn len(&self) -> usize {
+        self.children.len()
+    }
+
+    /// This is `true` if this page of the listing has no items.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<T> BasicThing<Listing<T>> {
+    /// The number of items on this page of the listing.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// This is `true` if this page of the listing has no items, e.g. a feed of a
+    /// subreddit without posts. A failed request is an `Err` instead.
+    ///
+    /// ```
+    /// use roux::subreddit::responses::Submissions;
+    ///
+    /// let body = r#"{"kind": "Listing", "data": {"after": null, "before": null, "children": []}}"#;
+    /// let submissions: Submissions = serde_json::from_str(body).unwrap();
+    ///
+    /// assert!(submissions.is_empty());
+    /// assert_eq!(submissions.len(), 0);
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
 /// Often times a basic thing will have this structure.
+/// Endpoints returning a listing of things (submissions, comments, messages...) share this
+/// envelope, e.g. `Submissions` is `BasicListing<SubmissionsData>`.
 pub type BasicListing<T> = BasicThing<Listing<BasicThing<T>>>;
+
+/// Body of responses from endpoints called with `api_type=json`.
+#[derive(Deserialize, Debug)]
+pub struct ApiResponse<T> {
+    /// The wrapped response.
+    pub json: ApiResponseData<T>,
+}
+
+impl<T> ApiResponse<T> {
+    /// The first of the errors, if the request was rejected.
+    pub(crate) fn check(&self) -> Result<(), RouxError> {
+        match self.json.errors.first() {
+            Some((code, message, field)) => Err(RouxError::Api(ApiError {
+                kind: ApiErrorKind::from(&code[..]),
+                message: message.to_owned(),
+                field: field.to_owned(),
+            })),
+            None => Ok(()),
+        }
+    }
+
+    /// The data, or the first of the errors if the request was rejected.
+    pub(crate) fn into_result(self) -> Result<T, RouxError> {
+        self.check()?;
+
+        self.json
+            .data
+            .ok_or_else(|| RouxError::Parse(de::Error::missing_field("data")))
+    }
+}
+
+/// Contents of an `ApiResponse`.
+#[derive(Deserialize, Debug)]
+pub struct ApiResponseData<T> {
+    /// Errors as `(code, message, field)`. Empty if the request succeeded.
+    #[serde(default)]
+    pub errors: Vec<(String, String, Option<String>)>,
+    /// The data returned by the endpoint. Missing if there are errors, and for endpoints
+    /// that only report success.
+    pub data: Option<T>,
+}
+
+/// Whether a post or comment was edited, which Reddit sends as either `false` or the time
+/// of the edit.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Edited {
+    /// Not edited.
+    #[default]
+    No,
+    /// Edited at an unknown time. Reddit sent `true` instead of a timestamp for some
+    /// old content.
+    Yes,
+    /// Edited at this timestamp, in **UTC**.
+    At(f64),
+}
+
+impl Edited {
+    /// This is `true` if the content was edited.
+    pub fn is_edited(&self) -> bool {
+        *self != Edited::No
+    }
+
+    /// The time of the edit as a `DateTime<Utc>`, if known.
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Option<DateTime<Utc>> {
+        match *self {
+            Edited::At(timestamp) => crate::util::time::from_timestamp(timestamp),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawEdited {
+    Bool(bool),
+    At(f64),
+}
+
+impl<'de> Deserialize<'de> for Edited {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match RawEdited::deserialize(deserializer)? {
+            RawEdited::Bool(false) => Edited::No,
+            RawEdited::Bool(true) => Edited::Yes,
+            RawEdited::At(timestamp) => Edited::At(timestamp),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Edited;
+
+    #[test]
+    fn test_edited() {
+        let edited: Edited = serde_json::from_str("false").unwrap();
+        assert_eq!(edited, Edited::No);
+        assert!(!edited.is_edited());
+
+        let edited: Edited = serde_json::from_str("1600000000.0").unwrap();
+        assert_eq!(edited, Edited::At(1600000000.0));
+        assert!(edited.is_edited());
+
+        let edited: Edited = serde_json::from_str("1600000000").unwrap();
+        assert_eq!(edited, Edited::At(1600000000.0));
+
+        let edited: Edited = serde_json::from_str("true").unwrap();
+        assert_eq!(edited, Edited::Yes);
+
+        assert!(serde_json::from_str::<Edited>(r#""yesterday""#).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_edited_datetime() {
+        assert_eq!(
+            Edited::At(1600000000.0)
+                .datetime()
+                .map(|time| time.timestamp()),
+            Some(1600000000)
+        );
+        assert_eq!(Edited::No.datetime(), None);
+    }
+}
@@ -0,0 +1,172 @@
+//! # Search
+//! A read-only module to search all of Reddit, rather than a single subreddit.
+//!
+//! # Usage
+//! ```rust
+//! use roux::Search;
+//! use roux::util::{SearchOptions, SearchSort, TimeFilter};
+//! use tokio;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let search = Search::new();
+//!     // Now you are able to:
+//!
+//!     // Search posts in every subreddit.
+//!     let options = SearchOptions::new().sort(SearchSort::Top).timefilter(TimeFilter::Week);
+//!     let posts = search.posts("rust", Some(options)).await;
+//!
+//!     // Search subreddits by name and description.
+//!     let subreddits = search.subreddits("rust", None).await;
+//! }
+//! ```
+
+extern crate reqwest;
+extern crate serde_json;
+
+use crate::subreddit::responses::{Submissions, SubredditListing};
+use crate::util::request::Sender;
+use crate::util::{RateLimit, RouxError, SearchOptions};
+use reqwest::Client;
+
+/// Search.
+pub struct Search {
+    sender: Sender,
+}
+
+impl Default for Search {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Search {
+    /// Create a new `Search` instance.
+    pub fn new() -> Search {
+        Self::new_with_http_client(Client::new())
+    }
+
+    /// Create a new `Search` instance with a provided HTTP client.
+    pub fn new_with_http_client(http_client: Client) -> Search {
+        Search {
+            sender: Sender::new(http_client),
+        }
+    }
+
+    /// Retry rate limited requests, see `Subreddit::with_max_retries`.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Search {
+        self.sender.max_retries = max_retries;
+        self
+    }
+
+    /// Rate limit state reported with the last response, see `Subreddit::last_rate_limit`.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.sender.last_rate_limit()
+    }
+
+    /// Search posts in every subreddit.
+    pub async fn posts(
+        &self,
+        query: &str,
+        options: Option<SearchOptions>,
+    ) -> Result<Submissions, RouxError> {
+        let options = options.unwrap_or_default();
+        let mut params = vec![("q", query.to_owned())];
+
+        if options.search_type.is_none() {
+            params.push(("type", "link".to_owned()));
+        }
+
+        params.extend(options.params());
+
+        self.sender
+            .get("https://www.reddit.com/search.json", &params)
+            .await
+    }
+
+    /// Search subreddits by name and description.
+    ///
+    /// Pass `options` to sort, limit or page the results.
+    pub async fn subreddits(
+        &self,
+        query: &str,
+        options: Option<SearchOptions>,
+    ) -> Result<SubredditListing, RouxError> {
+        let mut params = vec![("q", query.to_owned())];
+
+        if let Some(options) = options {
+            params.extend(options.params());
+        }
+
+        self.sender
+            .get("https://www.reddit.com/subreddits/search.json", &params)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Search;
+    use crate::util::transport::tests::{response, MockTransport};
+    use crate::util::{SearchOptions, SearchSort, TimeFilter};
+    use std::sync::Arc;
+    use tokio;
+
+    #[tokio::test]
+    async fn test_retries() {
+        let mut limited = response(429, "");
+        limited
+            .headers_mut()
+            .insert("retry-after", "0".parse().unwrap());
+        let empty = response(200, r#"{"kind": "Listing", "data": {"children": []}}"#);
+
+        let transport = Arc::new(MockTransport::new(vec![limited, empty]));
+        let mut search = Search::new().with_max_retries(1);
+        search.sender.transport = transport.clone();
+
+        let posts = search.posts("rust", None).await.unwrap();
+        assert!(posts.is_empty());
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].url().query().unwrap().contains("type=link"));
+        assert!(requests[1].url().query().unwrap().contains("raw_json=1"));
+    }
+
+    #[tokio::test]
+    async fn test_no_auth() {
+        let search = Search::new();
+
+        // Test posts
+        let options = SearchOptions::new()
+            .sort(SearchSort::Top)
+            .timefilter(TimeFilter::Year)
+            .limit(10);
+        let posts = search.posts("rust", Some(options)).await;
+        assert!(posts.is_ok());
+
+        let subreddits: Vec<_> = posts
+            .unwrap()
+            .data
+            .children
+            .into_iter()
+            .map(|post| post.data.subreddit)
+            .collect();
+        assert!(subreddits
+            .iter()
+            .any(|subreddit| subreddit != &subreddits[0]));
+
+        // Test subreddits
+        let subreddits = search
+            .subreddits("rust", Some(SearchOptions::new().limit(5)))
+            .await;
+        assert!(subreddits.is_ok());
+
+        let subreddits = subreddits.unwrap();
+        assert!(subreddits
+            .data
+            .children
+            .iter()
+            .any(|subreddit| subreddit.data.display_name == "rust"));
+    }
+}
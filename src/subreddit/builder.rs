@@ -0,0 +1,184 @@
+//! # Subreddit Builder
+//! Configuration for `Subreddit` instances.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Client, Proxy};
+
+use super::Subreddit;
+use crate::util::{RouxError, Transport};
+
+/// Builder for a `Subreddit` instance.
+///
+/// ```no_run
+/// use roux::subreddit::SubredditBuilder;
+/// use std::time::Duration;
+///
+/// let subreddit = SubredditBuilder::new("rust")
+///     .user_agent("macos:roux:v0.3.0 (by /u/beanpup_py)")
+///     .timeout(Duration::from_secs(10))
+///     .max_retries(3)
+///     .build();
+/// ```
+pub struct SubredditBuilder {
+    name: String,
+    client: Option<Client>,
+    user_agent: Option<String>,
+    proxy: Option<Proxy>,
+    timeout: Option<Duration>,
+    max_retries: u32,
+    allow_nsfw: bool,
+    cache: bool,
+    base_url: String,
+    transport: Option<Arc<dyn Transport>>,
+}
+
+impl SubredditBuilder {
+    /// Create a new `SubredditBuilder` for the subreddit `name`.
+    pub fn new(name: &str) -> SubredditBuilder {
+        SubredditBuilder {
+            name: name.to_owned(),
+            client: None,
+            user_agent: None,
+            proxy: None,
+            timeout: None,
+            max_retries: 0,
+            allow_nsfw: false,
+            cache: false,
+            base_url: "https://www.reddit.com".to_owned(),
+            transport: None,
+        }
+    }
+
+    /// Create a new `SubredditBuilder` for the subreddit `name` using a provided HTTP
+    /// client, see `client`.
+    ///
+    /// ```no_run
+    /// use roux::subreddit::SubredditBuilder;
+    /// use reqwest::Client;
+    ///
+    /// let client = Client::new();
+    /// let subreddit = SubredditBuilder::from_client(client, "rust").build();
+    /// ```
+    pub fn from_client(client: Client, name: &str) -> SubredditBuilder {
+        Self::new(name).client(client)
+    }
+
+    /// Sets the name of the subreddit.
+    pub fn name(mut self, name: &str) -> SubredditBuilder {
+        self.name = name.to_owned();
+        self
+    }
+
+    /// Use a provided HTTP client instead of building one. The `user_agent` and `proxy`
+    /// options only apply to a built client, configure them on `client` instead.
+    pub fn client(mut self, client: Client) -> SubredditBuilder {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the `User-Agent` header, see `Subreddit::new_with_user_agent`.
+    pub fn user_agent(mut self, user_agent: &str) -> SubredditBuilder {
+        self.user_agent = Some(user_agent.to_owned());
+        self
+    }
+
+    /// Sends requests through a proxy, e.g. `Proxy::all("http://proxy.example.com:3128")`.
+    /// Without it only proxies from the `HTTP_PROXY` and `HTTPS_PROXY` environment
+    /// variables are used.
+    pub fn proxy(mut self, proxy: Proxy) -> SubredditBuilder {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets a timeout for each request, see `Subreddit::with_timeout`.
+    pub fn timeout(mut self, timeout: Duration) -> SubredditBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum retries of rate limited requests, see `Subreddit::with_max_retries`.
+    pub fn max_retries(mut self, max_retries: u32) -> SubredditBuilder {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Confirms the user is an adult, see `Subreddit::with_nsfw_allowed`.
+    pub fn allow_nsfw(mut self) -> SubredditBuilder {
+        self.allow_nsfw = true;
+        self
+    }
+
+    /// Caches responses, see `Subreddit::with_cache`.
+    pub fn cache(mut self) -> SubredditBuilder {
+        self.cache = true;
+        self
+    }
+
+    /// Sets the host requests are sent to, `https://www.reddit.com` by default.
+    /// Useful for pointing at a mock server in tests. For requests made as a logged
+    /// in user (which go to `https://oauth.reddit.com`) use `Me::subreddit` instead.
+    pub fn base_url(mut self, base_url: &str) -> SubredditBuilder {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Sends requests through `transport`, see `Subreddit::with_transport`.
+    pub fn transport<T: Transport + 'static>(mut self, transport: T) -> SubredditBuilder {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Create the `Subreddit` instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the HTTP client can't be built, e.g. because the `user_agent` has a
+    /// character a header can't contain, such as a newline. Use `try_build` to handle it.
+    pub fn build(self) -> Subreddit {
+        self.try_build().unwrap()
+    }
+
+    /// Create the `Subreddit` instance, failing with `RouxError::Network` if the HTTP
+    /// client can't be built, e.g. because of an invalid `user_agent`.
+    pub fn try_build(self) -> Result<Subreddit, RouxError> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder();
+
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+
+                if let Some(proxy) = self.proxy {
+                    builder = builder.proxy(proxy);
+                }
+
+                builder.build()?
+            }
+        };
+
+        let mut subreddit = Subreddit::new_with_host(&self.name, client, &self.base_url)
+            .with_max_retries(self.max_retries);
+
+        if self.allow_nsfw {
+            subreddit = subreddit.with_nsfw_allowed();
+        }
+
+        if self.cache {
+            subreddit = subreddit.with_cache();
+        }
+
+        if let Some(transport) = self.transport {
+            subreddit.sender.transport = transport;
+        }
+
+        if let Some(timeout) = self.timeout {
+            subreddit = subreddit.with_timeout(timeout);
+        }
+
+        Ok(subreddit)
+    }
+}
@@ -12,7 +12,10 @@
 //!     // Now you are able to:
 //!
 //!     // Get moderators.
-//!     let moderators = subreddit.moderators().await;
+//!     let moderators = subreddit.moderators(None).await;
+//!
+//!     // Get subscriber count, description, etc.
+//!     let about = subreddit.about().await;
 //!
 //!     // Get hot posts with limit = 25.
 //!     let hot = subreddit.hot(25, None).await;
@@ -23,13 +26,22 @@
 //!     // Get top posts with limit = 10.
 //!     let top = subreddit.top(10, None).await;
 //!
+//!     // Get controversial posts with limit = 10.
+//!     let controversial = subreddit.controversial(10, None).await;
+//!
+//!     // Get awarded posts with limit = 10.
+//!     let gilded = subreddit.gilded(10, None).await;
+//!
+//!     // Get best posts with limit = 10.
+//!     let best = subreddit.best(10, None).await;
+//!
 //!     // Get latest comments.
 //!     // `depth` and `limit` are optional.
 //!     let latest_comments = subreddit.latest_comments(None, Some(25)).await;
 //!
 //!     // Get comments from a submission.
 //!     let article_id = &hot.unwrap().data.children.first().unwrap().data.id.clone();
-//!     let article_comments = subreddit.article_comments(article_id, None, Some(25));
+//!     let article_comments = subreddit.article_comments(article_id, None, Some(25), None);
 //! }
 //! ```
 //!
@@ -37,7 +49,7 @@
 //!
 //! ```rust
 //! use roux::Subreddit;
-//! use roux::util::FeedOption;
+//! use roux::util::{FeedOption, SearchOptions, SearchSort, TimeFilter};
 //! use tokio;
 //!
 //! #[tokio::main]
@@ -53,127 +65,524 @@
 //!
 //!     // Gets next 25
 //!     let next_hot = subreddit.hot(25, Some(options)).await;
+//!
+//!     // Gets top 10 posts of the year
+//!     let options = FeedOption::new().timefilter(TimeFilter::Year);
+//!     let top_year = subreddit.top(10, Some(options)).await;
+//!
+//!     // Search for newest posts
+//!     let options = SearchOptions::new().sort(SearchSort::New);
+//!     let search = subreddit.search("cosplay", Some(options)).await;
 //! }
 //! ```
 
 extern crate reqwest;
 extern crate serde_json;
 
-use crate::util::{FeedOption, RouxError};
-use reqwest::Client;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::auth::Auth;
+use crate::responses::{ApiResponse, BasicThing, Items, Listing};
+use crate::util::cache::{self, ResponseCache};
+use crate::util::request::Sender;
+use crate::util::{
+    BanOptions, CommentSort, FeedOption, RateLimit, RouxError, SearchOptions, SearchSort, Transport,
+};
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Serialize;
+use serde_json::Value;
+
+mod builder;
+pub use builder::SubredditBuilder;
 
 pub mod responses;
-use responses::{SubredditComments, Moderators, Submissions};
+mod stream;
+use responses::{
+    About, AboutData, Collection, Emoji, FlairTemplate, Moderators, MoreChildren, Rules,
+    Submissions, SubmissionsData, Submitted, SubmittedPost, SubredditComments,
+    SubredditCommentsData, Traffic, Wiki, WikiPage, WikiPages,
+};
+
+/// Maximum number of characters of flair text.
+const FLAIR_TEXT_LIMIT: usize = 64;
+
+/// Maximum number of fullnames `/by_id` accepts per request.
+const BY_ID_LIMIT: usize = 100;
+
+/// Maximum number of comment IDs `/api/morechildren` accepts per request.
+const MORE_CHILDREN_LIMIT: usize = 100;
 
 /// Subreddit.
+///
+/// Clones share the HTTP client's connection pool, as well as the rate limit state and
+/// response cache, so a clone can be moved to each task that reads the subreddit.
+#[derive(Clone)]
 pub struct Subreddit {
     /// Name of subreddit.
     pub name: String,
     url: String,
-    client: Client,
+    host: String,
+    sender: Sender,
+    auth: Option<Arc<Auth>>,
 }
 
 impl Subreddit {
     /// Create a new `Subreddit` instance.
+    ///
+    /// The name isn't checked, see `try_new` to catch typos before making requests.
     pub fn new(name: &str) -> Subreddit {
         Self::new_with_http_client(name, Client::new())
     }
 
+    /// Create a new `Subreddit` instance, failing with `RouxError::InvalidArgument` if
+    /// `name` can't be the name of a subreddit, e.g. because it's empty or has spaces or
+    /// slashes in it.
+    ///
+    /// Names may only have letters, digits and underscores, except for combined
+    /// subreddits like `rust+golang` (see `combined`).
+    pub fn try_new(name: &str) -> Result<Subreddit, RouxError> {
+        validate_name(name)?;
+        Ok(Self::new(name))
+    }
+
+    /// Create a new `Subreddit` instance sending a `User-Agent` header.
+    ///
+    /// Reddit throttles generic user agents, so it is important that you pick a good one.
+    /// The ideal format is `platform:program:version (by /u/yourname)`,
+    /// e.g. `macos:roux:v0.3.0 (by /u/beanpup_py)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `user_agent` can't be sent as a header, e.g. because it contains a
+    /// newline. Build with `SubredditBuilder::try_build` to handle that instead.
+    pub fn new_with_user_agent(name: &str, user_agent: &str) -> Subreddit {
+        SubredditBuilder::new(name).user_agent(user_agent).build()
+    }
+
+    /// Create a `Subreddit` instance combining several subreddits, e.g. `/r/rust+golang`.
+    /// Its feeds mix the posts of all of them.
+    pub fn combined(names: &[&str]) -> Subreddit {
+        Self::new(&names.join("+"))
+    }
+
+    /// Create a `SubredditBuilder` to configure a new `Subreddit` instance.
+    pub fn builder(name: &str) -> SubredditBuilder {
+        SubredditBuilder::new(name)
+    }
+
     /// Create a new `Subreddit` instance with a provided HTTP client.
     pub fn new_with_http_client(name: &str, http_client: Client) -> Subreddit {
-        let subreddit_url = format!("https://www.reddit.com/r/{}", name);
+        Self::new_with_host(name, http_client, "https://www.reddit.com")
+    }
 
+    /// Create a new `Subreddit` instance with an OAuth client, see `Me::subreddit`.
+    pub(crate) fn new_oauth(name: &str, http_client: Client, auth: Arc<Auth>) -> Subreddit {
+        Subreddit {
+            auth: Some(auth),
+            ..Self::new_with_host(name, http_client, "https://oauth.reddit.com")
+        }
+    }
+
+    fn new_with_host(name: &str, http_client: Client, host: &str) -> Subreddit {
         Subreddit {
             name: name.to_owned(),
-            url: subreddit_url,
-            client: http_client,
+            url: format!("{}/r/{}", host, name),
+            host: host.to_owned(),
+            sender: Sender::new(http_client),
+            auth: None,
         }
     }
 
-    /// Get moderators.
-    pub async fn moderators(&self) -> Result<Moderators, RouxError> {
-        Ok(self
-            .client
-            .get(&format!("{}/about/moderators/.json", self.url))
-            .send()
+    /// Retry requests up to `max_retries` times when rate limited, waiting as long as
+    /// Reddit asks to between attempts. Requests aren't retried by default.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Subreddit {
+        self.sender.max_retries = max_retries;
+        self
+    }
+
+    /// Give up on requests that take longer than `timeout`, failing with a
+    /// `RouxError::Network` error. Requests never time out by default.
+    ///
+    /// Each attempt of a retried request gets the whole `timeout`.
+    pub fn with_timeout(mut self, timeout: Duration) -> Subreddit {
+        self.sender.timeout = Some(timeout);
+        self
+    }
+
+    /// Send the `over18=1` cookie with requests, confirming the user is an adult. Without
+    /// it Reddit may hide the content of NSFW subreddits from logged out users.
+    ///
+    /// Quarantined subreddits also need an opt-in, see `quarantine_optin`.
+    pub fn with_nsfw_allowed(mut self) -> Subreddit {
+        self.sender.allow_nsfw = true;
+        self
+    }
+
+    /// Remember the bodies of responses to `GET` requests with an `ETag`, for the last
+    /// hundred URLs. Requesting one of them again asks Reddit to answer `304 Not Modified`
+    /// if nothing changed, and reuses the remembered body, which saves bandwidth when
+    /// polling feeds.
+    pub fn with_cache(mut self) -> Subreddit {
+        let cache = ResponseCache::new(cache::DEFAULT_CAPACITY);
+        self.sender.cache = Some(Arc::new(Mutex::new(cache)));
+        self
+    }
+
+    /// Send requests through `transport` instead of the HTTP client, e.g. to answer them
+    /// with canned responses in tests. See `util::transport`.
+    ///
+    /// The HTTP client only adds its default headers, such as its `User-Agent`, to the
+    /// requests it sends itself, so other transports don't send them.
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Subreddit {
+        self.sender.transport = Arc::new(transport);
+        self
+    }
+
+    /// Rate limit state reported with the last response, to throttle requests before
+    /// Reddit starts rejecting them.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.sender.last_rate_limit()
+    }
+
+    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T, RouxError> {
+        self.send(self.sender.client.get(url)).await
+    }
+
+    /// Post a form to `{host}/{path}` as the logged in user.
+    async fn post<T: DeserializeOwned, F: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        form: &F,
+    ) -> Result<T, RouxError> {
+        if self.auth.is_none() {
+            return Err(RouxError::Unauthenticated);
+        }
+
+        let url = format!("{}/{}", self.host, path);
+        self.send(self.sender.client.post(&url).form(form)).await
+    }
+
+    /// Post a form as the logged in user, ignoring the response on success.
+    async fn post_action<F: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        form: &F,
+    ) -> Result<(), RouxError> {
+        self.post::<IgnoredAny, _>(path, form).await?;
+        Ok(())
+    }
+
+    async fn send<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, RouxError> {
+        self.sender.send(request, self.auth.as_deref()).await
+    }
+
+    /// Get moderators. Large subreddits have more than one page of moderators; pass the
+    /// `after` of the previous page in `options` to get the next one.
+    pub async fn moderators(&self, options: Option<FeedOption>) -> Result<Moderators, RouxError> {
+        let url = format!("{}/about/moderators/.json", self.url);
+        let params = options.map(|options| options.params()).unwrap_or_default();
+
+        self.send(self.sender.client.get(&url).query(&params)).await
+    }
+
+    /// Get subreddit metadata.
+    pub async fn about(&self) -> Result<AboutData, RouxError> {
+        let about = self
+            .get::<About>(&format!("{}/about.json", self.url))
+            .await?;
+
+        Ok(about.data)
+    }
+
+    /// Get rules.
+    pub async fn rules(&self) -> Result<Rules, RouxError> {
+        self.get(&format!("{}/about/rules.json", self.url)).await
+    }
+
+    /// Get a page of the subreddit's wiki, e.g. `index`.
+    ///
+    /// Wikis and pages only visible to approved contributors or moderators fail with
+    /// `RouxError::Forbidden`.
+    pub async fn wiki_page(&self, page: &str) -> Result<WikiPage, RouxError> {
+        let url = format!("{}/wiki/{}.json", self.url, page);
+        let wiki = forbidden(self.get::<Wiki>(&url).await)?;
+
+        Ok(wiki.data)
+    }
+
+    /// Get the names of the pages of the subreddit's wiki.
+    pub async fn wiki_pages(&self) -> Result<Vec<String>, RouxError> {
+        let pages = self
+            .get::<WikiPages>(&format!("{}/wiki/pages.json", self.url))
+            .await?;
+
+        Ok(pages.data)
+    }
+
+    /// Get the flair templates that can be used on posts.
+    pub async fn link_flair_templates(&self) -> Result<Vec<FlairTemplate>, RouxError> {
+        self.get(&format!("{}/api/link_flair_v2.json", self.url))
+            .await
+    }
+
+    /// Get the flair templates that can be used by users.
+    pub async fn user_flair_templates(&self) -> Result<Vec<FlairTemplate>, RouxError> {
+        self.get(&format!("{}/api/user_flair_v2.json", self.url))
+            .await
+    }
+
+    /// Get the numbers of visitors and page views of the subreddit per hour, day and month.
+    ///
+    /// Fails with `RouxError::Forbidden` unless the user moderates the subreddit, or the
+    /// subreddit made its traffic public.
+    pub async fn traffic(&self) -> Result<Traffic, RouxError> {
+        let url = format!("{}/about/traffic.json", self.url);
+
+        forbidden(self.get(&url).await)
+    }
+
+    /// Get the custom emojis that can be used in the subreddit, by name (as in `:name:`).
+    /// Reddit's own emojis are included.
+    pub async fn emojis(&self) -> Result<HashMap<String, Emoji>, RouxError> {
+        let url = format!("{}/api/v1/{}/emojis/all", self.host, self.name);
+
+        // Emojis are grouped by owner: `snoomojis` for Reddit's own, and the subreddit's
+        // fullname for the rest.
+        let groups = self
+            .get::<HashMap<String, HashMap<String, Emoji>>>(&url)
+            .await?;
+
+        Ok(groups.into_values().flatten().collect())
+    }
+
+    /// Flair a post by its fullname, e.g. `t3_abc`, with one of the `link_flair_templates`.
+    /// Needs a subreddit from `Me::subreddit`.
+    pub async fn set_flair(&self, fullname: &str, template_id: &str) -> Result<(), RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("link", fullname),
+            ("flair_template_id", template_id),
+        ];
+
+        self.post::<ApiResponse<IgnoredAny>, _>(&format!("r/{}/api/selectflair", self.name), &form)
             .await?
-            .json::<Moderators>()
-            .await?)
+            .check()
+    }
+
+    /// Post a form for a moderator action, which Reddit rejects with a `403` status if the
+    /// user doesn't moderate the subreddit or lacks the permission.
+    async fn post_mod_action<F: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        form: &F,
+    ) -> Result<(), RouxError> {
+        forbidden(self.post::<ApiResponse<IgnoredAny>, _>(path, form).await)?.check()
+    }
+
+    /// Ban a user from the subreddit. Needs a subreddit from `Me::subreddit`.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate the subreddit, and
+    /// `ApiErrorKind::UserNotFound` if there is no user named `username`.
+    pub async fn ban_user(&self, username: &str, options: BanOptions) -> Result<(), RouxError> {
+        let mut form = vec![
+            ("api_type", "json".to_owned()),
+            ("type", "banned".to_owned()),
+            ("name", username.to_owned()),
+        ];
+        form.extend(options.params());
+
+        self.post_mod_action(&format!("r/{}/api/friend", self.name), &form)
+            .await
+    }
+
+    /// Unban a user from the subreddit. Needs a subreddit from `Me::subreddit`.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate the subreddit.
+    pub async fn unban_user(&self, username: &str) -> Result<(), RouxError> {
+        let form = [("api_type", "json"), ("type", "banned"), ("name", username)];
+
+        self.post_mod_action(&format!("r/{}/api/unfriend", self.name), &form)
+            .await
+    }
+
+    /// Set the user flair of `username` in the subreddit. Needs a subreddit from
+    /// `Me::subreddit`.
+    ///
+    /// Text longer than 64 characters fails with `RouxError::InvalidArgument` before any
+    /// request is made, and `RouxError::Forbidden` if the user doesn't moderate the
+    /// subreddit.
+    pub async fn set_user_flair(
+        &self,
+        username: &str,
+        text: &str,
+        css_class: Option<&str>,
+    ) -> Result<(), RouxError> {
+        if text.chars().count() > FLAIR_TEXT_LIMIT {
+            return Err(RouxError::InvalidArgument(format!(
+                "flair text is longer than {} characters",
+                FLAIR_TEXT_LIMIT
+            )));
+        }
+
+        let form = [
+            ("api_type", "json"),
+            ("name", username),
+            ("text", text),
+            ("css_class", css_class.unwrap_or("")),
+        ];
+
+        self.post_mod_action(&format!("r/{}/api/flair", self.name), &form)
+            .await
+    }
+
+    /// Get a single submission by its ID, with or without the `t3_` prefix.
+    pub async fn submission(&self, id: &str) -> Result<SubmissionsData, RouxError> {
+        let url = format!("{}/by_id/{}.json", self.host, fullname("t3", id));
+        let mut submissions = self.get::<Submissions>(&url).await?;
+
+        match submissions.data.children.pop() {
+            Some(submission) => Ok(submission.data),
+            None => Err(not_found()),
+        }
+    }
+
+    /// Get many submissions by their IDs, with or without the `t3_` prefix, in one
+    /// listing. Submissions are fetched in batches if there are more than Reddit allows
+    /// in one request.
+    ///
+    /// IDs of submissions that don't exist are left out of the listing.
+    pub async fn submissions(&self, ids: &[&str]) -> Result<Submissions, RouxError> {
+        let mut submissions: Option<Submissions> = None;
+
+        for chunk in ids.chunks(BY_ID_LIMIT) {
+            let names: Vec<_> = chunk.iter().map(|id| fullname("t3", id)).collect();
+            let url = format!("{}/by_id/{}.json", self.host, names.join(","));
+            let page = self.get::<Submissions>(&url).await?;
+
+            match &mut submissions {
+                Some(submissions) => submissions.data.children.extend(page.data.children),
+                None => submissions = Some(page),
+            }
+        }
+
+        Ok(submissions.unwrap_or_else(|| BasicThing {
+            kind: "Listing".to_owned(),
+            data: Listing {
+                modhash: None,
+                dist: None,
+                after: None,
+                before: None,
+                children: Vec::new(),
+            },
+        }))
+    }
+
+    /// Get a submission by a link to it, e.g. its permalink (`/r/rust/comments/abc/title/`,
+    /// as in `SubmissionsData::permalink`), a `redd.it` short link or an `old.reddit.com` link.
+    pub async fn submission_from_url(&self, url: &str) -> Result<SubmissionsData, RouxError> {
+        match crate::util::url::submission_id(url) {
+            Some(id) => self.submission(id).await,
+            None => Err(RouxError::InvalidArgument(format!(
+                "not a link to a submission: {}",
+                url
+            ))),
+        }
+    }
+
+    /// Get one of the (up to two) posts stickied to the top of the subreddit.
+    /// `num` must be 1 or 2.
+    pub async fn sticky(&self, num: u8) -> Result<SubmissionsData, RouxError> {
+        if num != 1 && num != 2 {
+            return Err(RouxError::InvalidArgument(format!(
+                "sticky number must be 1 or 2, got {}",
+                num
+            )));
+        }
+
+        // Reddit redirects to the sticky's comments page, with the post as the first element.
+        let url = format!("{}/about/sticky.json?num={}", self.url, num);
+        let (mut submission, _) = self.get::<(Submissions, SubredditComments)>(&url).await?;
+
+        match submission.data.children.pop() {
+            Some(submission) => Ok(submission.data),
+            None => Err(not_found()),
+        }
     }
 
-    async fn get_feed(
+    async fn get_feed<T: DeserializeOwned>(
         &self,
         ty: &str,
         limit: u32,
         options: Option<FeedOption>,
-    ) -> Result<Submissions, RouxError> {
-        let url = &mut format!("{}/{}.json?limit={}", self.url, ty, limit);
-
-        if !options.is_none() {
-            let option = options.unwrap();
-
-            if !option.after.is_none() {
-                url.push_str(&mut format!("&after={}", option.after.unwrap().to_owned()));
-            } else if !option.before.is_none() {
-                url.push_str(&mut format!(
-                    "&before={}",
-                    option.before.unwrap().to_owned()
-                ));
-            }
+    ) -> Result<T, RouxError> {
+        let url = format!("{}/{}.json", self.url, ty);
+        let mut params = vec![("limit", limit.to_string())];
 
-            if !option.count.is_none() {
-                url.push_str(&mut format!("&count={}", option.count.unwrap()));
-            }
+        if let Some(options) = options {
+            params.extend(options.params());
         }
 
-        Ok(self
-            .client
-            .get(&url.to_owned())
-            .send()
-            .await?
-            .json::<Submissions>()
-            .await?)
+        self.send(self.sender.client.get(&url).query(&params)).await
     }
 
-    async fn get_comment_feed(
+    /// The subreddit's comment feed is a single listing, but an article's comments come
+    /// as an array of the article's listing and the comments, so callers pick the `T`.
+    async fn get_comment_feed<T: DeserializeOwned>(
         &self,
         ty: &str,
         depth: Option<u32>,
         limit: Option<u32>,
-    ) -> Result<SubredditComments, RouxError> {
-        let url = &mut format!("{}/{}.json?", self.url, ty);
+        sort: Option<CommentSort>,
+    ) -> Result<T, RouxError> {
+        let url = format!("{}/{}.json", self.url, ty);
+        let mut params = Vec::new();
 
-        if !depth.is_none() {
-            url.push_str(&mut format!("&depth={}", depth.unwrap()));
+        if let Some(depth) = depth {
+            params.push(("depth", depth.to_string()));
         }
 
-        if !limit.is_none() {
-            url.push_str(&mut format!("&limit={}", limit.unwrap()));
+        if let Some(limit) = limit {
+            params.push(("limit", limit.to_string()));
         }
 
-        // This is one of the dumbest APIs I've ever seen.
-        // The comments for a subreddit are stored in a normal hash map
-        // but for posts the comments are in an array with the ONLY item
-        // being same hash map as the one for subreddits...
-        if url.contains("comments/") {
-            let mut comments = self
-                .client
-                .get(&url.to_owned())
-                .send()
-                .await?
-                .json::<Vec<SubredditComments>>()
-                .await?;
-
-            Ok(comments.pop().unwrap())
-        } else {
-            Ok(self
+        if let Some(sort) = sort {
+            params.push(("sort", sort.to_string()));
+        }
+
+        self.send(self.sender.client.get(&url).query(&params)).await
+    }
+
+    /// Load the comments hidden behind a `more` stub of an article's comment tree.
+    ///
+    /// `children` are the IDs listed in the stub. Comments are fetched in batches if
+    /// there are more than Reddit allows in one request.
+    pub async fn more_children(
+        &self,
+        link_id: &str,
+        children: &[String],
+    ) -> Result<Vec<BasicThing<SubredditCommentsData>>, RouxError> {
+        let link_id = fullname("t3", link_id);
+        let mut things = Vec::new();
+
+        for chunk in children.chunks(MORE_CHILDREN_LIMIT) {
+            let request = self
+                .sender
                 .client
-                .get(&url.to_owned())
-                .send()
-                .await?
-                .json::<SubredditComments>()
-                .await?)
+                .get(&format!("{}/api/morechildren.json", self.host))
+                .query(&[
+                    ("api_type", "json"),
+                    ("link_id", &link_id),
+                    ("children", &chunk.join(",")),
+                ]);
+
+            let more = self.send::<MoreChildren>(request).await?.into_result()?;
+            things.extend(more.things);
         }
+
+        Ok(things)
     }
 
     /// Get hot posts.
@@ -185,6 +594,27 @@ impl Subreddit {
         self.get_feed("hot", limit, options).await
     }
 
+    /// Get a feed (`hot`, `rising`, `top`, `new`, `controversial`, `gilded` or `best`) as untyped
+    /// JSON, for fields that `Submissions` doesn't have yet.
+    ///
+    /// ```no_run
+    /// # use roux::Subreddit;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let hot = Subreddit::new("rust").feed_raw("hot", 25, None).await.unwrap();
+    /// let first = &hot["data"]["children"][0]["data"];
+    /// println!("{}", first["upvote_ratio"]);
+    /// # }
+    /// ```
+    pub async fn feed_raw(
+        &self,
+        ty: &str,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Value, RouxError> {
+        self.get_feed(ty, limit, options).await
+    }
+
     /// Get rising posts.
     pub async fn rising(
         &self,
@@ -200,7 +630,6 @@ impl Subreddit {
         limit: u32,
         options: Option<FeedOption>,
     ) -> Result<Submissions, RouxError> {
-        // TODO: time filter
         self.get_feed("top", limit, options).await
     }
 
@@ -213,40 +642,414 @@ impl Subreddit {
         self.get_feed("new", limit, options).await
     }
 
+    /// Get controversial posts.
+    pub async fn controversial(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        self.get_feed("controversial", limit, options).await
+    }
+
+    /// Get posts that received awards.
+    pub async fn gilded(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        self.get_feed("gilded", limit, options).await
+    }
+
+    /// Get the best posts. Personalized for the logged in user on a subreddit from
+    /// `Me::subreddit`, otherwise ranked like for a logged out visitor.
+    pub async fn best(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<Submissions, RouxError> {
+        self.get_feed("best", limit, options).await
+    }
+
+    async fn get_search<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        options: Option<SearchOptions>,
+    ) -> Result<T, RouxError> {
+        let options = options.unwrap_or_default();
+        let mut params = vec![("q", query.to_owned())];
+
+        if options.restrict_sr.is_none() {
+            params.push(("restrict_sr", "true".to_owned()));
+        }
+
+        params.extend(options.params());
+
+        let request = self
+            .sender
+            .client
+            .get(&format!("{}/search.json", self.url))
+            .query(&params);
+
+        self.send(request).await
+    }
+
+    /// Search for posts in this subreddit, or all of Reddit if `restrict_sr` is `false`.
+    pub async fn search(
+        &self,
+        query: &str,
+        options: Option<SearchOptions>,
+    ) -> Result<Submissions, RouxError> {
+        self.get_search(query, options).await
+    }
+
+    /// Get the latest posts with the link flair `flair_text`, e.g. `Help`, newest first.
+    pub async fn hot_by_flair(
+        &self,
+        limit: u32,
+        flair_text: &str,
+    ) -> Result<Submissions, RouxError> {
+        // Quotes can't be escaped in the search syntax.
+        let query = format!("flair_name:\"{}\"", flair_text.replace('"', ""));
+        let options = SearchOptions::new().sort(SearchSort::New).limit(limit);
+
+        self.search(&query, Some(options)).await
+    }
+
+    /// Search like `search` as untyped JSON, for a `search_type` other than posts.
+    pub async fn search_raw(
+        &self,
+        query: &str,
+        options: Option<SearchOptions>,
+    ) -> Result<Value, RouxError> {
+        self.get_search(query, options).await
+    }
+
     /// Get latest comments.
     pub async fn latest_comments(
         &self,
         depth: Option<u32>,
         limit: Option<u32>,
     ) -> Result<SubredditComments, RouxError> {
-        self.get_comment_feed("comments", depth, limit).await
+        self.get_comment_feed("comments", depth, limit, None).await
     }
 
     /// Get comments from article.
+    ///
+    /// Without a `sort`, comments are in the article's `suggested_sort` if it has one,
+    /// and best first otherwise. Parse `suggested_sort` into a `CommentSort` to pick
+    /// the same order explicitly.
     pub async fn article_comments(
         &self,
         article: &str,
         depth: Option<u32>,
         limit: Option<u32>,
+        sort: Option<CommentSort>,
     ) -> Result<SubredditComments, RouxError> {
-        self.get_comment_feed(&format!("comments/{}", article), depth, limit)
-            .await
+        let (_, comments) = self
+            .get_comment_feed::<(Submissions, SubredditComments)>(
+                &format!("comments/{}", article),
+                depth,
+                limit,
+                sort,
+            )
+            .await?;
+
+        Ok(comments)
+    }
+
+    /// Get an article along with its comments, like `article_comments`, in one request.
+    pub async fn article_with_comments(
+        &self,
+        article: &str,
+        depth: Option<u32>,
+        limit: Option<u32>,
+        sort: Option<CommentSort>,
+    ) -> Result<(SubmissionsData, SubredditComments), RouxError> {
+        let (mut submission, comments) = self
+            .get_comment_feed::<(Submissions, SubredditComments)>(
+                &format!("comments/{}", article),
+                depth,
+                limit,
+                sort,
+            )
+            .await?;
+
+        match submission.data.children.pop() {
+            Some(submission) => Ok((submission.data, comments)),
+            None => Err(not_found()),
+        }
+    }
+
+    /// Submit a text post. Needs a subreddit from `Me::subreddit`.
+    ///
+    /// Reddit rejecting the post, e.g. for posting too often or needing a captcha,
+    /// is returned as `RouxError::Api`.
+    pub async fn submit_self(&self, title: &str, text: &str) -> Result<SubmittedPost, RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("kind", "self"),
+            ("sr", &self.name),
+            ("title", title),
+            ("text", text),
+        ];
+
+        self.post::<Submitted, _>("api/submit", &form)
+            .await?
+            .into_result()
+    }
+
+    /// Submit a link post. Needs a subreddit from `Me::subreddit`.
+    ///
+    /// Links that were already submitted to the subreddit are rejected with
+    /// `ApiErrorKind::AlreadySubmitted`, unless `resubmit` is set.
+    pub async fn submit_link(
+        &self,
+        title: &str,
+        url: &str,
+        resubmit: bool,
+    ) -> Result<SubmittedPost, RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("kind", "link"),
+            ("sr", &self.name),
+            ("title", title),
+            ("url", url),
+            ("resubmit", if resubmit { "true" } else { "false" }),
+        ];
+
+        self.post::<Submitted, _>("api/submit", &form)
+            .await?
+            .into_result()
+    }
+
+    /// Crosspost the submission `source_fullname` (e.g. `t3_abc123`) to the subreddit.
+    /// Needs a subreddit from `Me::subreddit`.
+    ///
+    /// Subreddits that don't allow crossposts reject it with
+    /// `ApiErrorKind::CrosspostNotAllowed`.
+    pub async fn submit_crosspost(
+        &self,
+        title: &str,
+        source_fullname: &str,
+    ) -> Result<SubmittedPost, RouxError> {
+        let form = [
+            ("api_type", "json"),
+            ("kind", "crosspost"),
+            ("sr", &self.name),
+            ("title", title),
+            ("crosspost_fullname", source_fullname),
+        ];
+
+        self.post::<Submitted, _>("api/submit", &form)
+            .await?
+            .into_result()
+    }
+
+    /// Subscribe the logged in user to the subreddit. Needs a subreddit from `Me::subreddit`.
+    ///
+    /// Fails with an error status if the subreddit doesn't exist.
+    pub async fn subscribe(&self) -> Result<(), RouxError> {
+        let form = [("action", "sub"), ("sr_name", &self.name)];
+        self.post_action("api/subscribe", &form).await
+    }
+
+    /// Opt the logged in user in to viewing the subreddit, if it's quarantined. Until then
+    /// its feeds fail with an error status. Needs a subreddit from `Me::subreddit`.
+    pub async fn quarantine_optin(&self) -> Result<(), RouxError> {
+        let form = [("sr_name", &self.name)];
+        self.post_action("api/quarantine_optin", &form).await
+    }
+
+    /// Unsubscribe the logged in user from the subreddit. Needs a subreddit from `Me::subreddit`.
+    pub async fn unsubscribe(&self) -> Result<(), RouxError> {
+        let form = [("action", "unsub"), ("sr_name", &self.name)];
+        self.post_action("api/subscribe", &form).await
+    }
+
+    async fn get_moderation_listing(
+        &self,
+        ty: &str,
+        options: Option<FeedOption>,
+    ) -> Result<Items, RouxError> {
+        if self.auth.is_none() {
+            return Err(RouxError::Unauthenticated);
+        }
+
+        let url = format!("{}/about/{}.json", self.url, ty);
+        let params = options.map(|options| options.params()).unwrap_or_default();
+
+        forbidden(self.send(self.sender.client.get(&url).query(&params)).await)
+    }
+
+    /// Get the posts and comments waiting for moderator review, whether reported or
+    /// caught by the spam filter. Needs a subreddit from `Me::subreddit`.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate the subreddit.
+    pub async fn modqueue(&self, options: Option<FeedOption>) -> Result<Items, RouxError> {
+        self.get_moderation_listing("modqueue", options).await
+    }
+
+    /// Get the reported posts and comments. Needs a subreddit from `Me::subreddit`.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate the subreddit.
+    pub async fn reports(&self, options: Option<FeedOption>) -> Result<Items, RouxError> {
+        self.get_moderation_listing("reports", options).await
+    }
+
+    /// Get the posts and comments removed as spam. Needs a subreddit from `Me::subreddit`.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate the subreddit.
+    pub async fn spam(&self, options: Option<FeedOption>) -> Result<Items, RouxError> {
+        self.get_moderation_listing("spam", options).await
+    }
+
+    /// Get the posts no moderator has approved or removed yet. Needs a subreddit from
+    /// `Me::subreddit`.
+    ///
+    /// Fails with `RouxError::Forbidden` if the user doesn't moderate the subreddit.
+    pub async fn unmoderated(&self, options: Option<FeedOption>) -> Result<Items, RouxError> {
+        self.get_moderation_listing("unmoderated", options).await
+    }
+
+    /// Get a single comment of an article, with up to `context` levels of its parents and
+    /// `depth` levels of its replies.
+    ///
+    /// The comment tree starts at its highest loaded parent, or the comment itself
+    /// without `context`.
+    pub async fn comment(
+        &self,
+        article: &str,
+        comment: &str,
+        context: Option<u32>,
+        depth: Option<u32>,
+    ) -> Result<SubredditComments, RouxError> {
+        let url = format!("{}/comments/{}/_/{}.json", self.url, article, comment);
+        let mut params = Vec::new();
+
+        if let Some(context) = context {
+            params.push(("context", context));
+        }
+
+        if let Some(depth) = depth {
+            params.push(("depth", depth));
+        }
+
+        let request = self.sender.client.get(&url).query(&params);
+        let (_, comments) = self
+            .send::<(Submissions, SubredditComments)>(request)
+            .await?;
+
+        Ok(comments)
+    }
+
+    /// Get a collection of posts by its ID, e.g. from the URL of the collection.
+    pub async fn collection(&self, collection_id: &str) -> Result<Collection, RouxError> {
+        let url = format!("{}/api/v1/collections/collection.json", self.host);
+        let params = [("collection_id", collection_id), ("include_links", "true")];
+
+        self.send(self.sender.client.get(&url).query(&params)).await
+    }
+
+    /// Get other submissions of the same link as `article`, from any subreddit.
+    pub async fn duplicates(&self, article: &str) -> Result<Submissions, RouxError> {
+        // Like article comments, this is an array of the article itself followed by the listing.
+        let url = format!("{}/duplicates/{}.json", self.url, article);
+        let (_, duplicates) = self.get::<(Submissions, Submissions)>(&url).await?;
+
+        Ok(duplicates)
+    }
+}
+
+impl From<&str> for Subreddit {
+    /// Same as `Subreddit::new`.
+    fn from(name: &str) -> Subreddit {
+        Subreddit::new(name)
+    }
+}
+
+/// Checks that `name` is a subreddit name, or several joined with `+`.
+fn validate_name(name: &str) -> Result<(), RouxError> {
+    let valid = name.split('+').all(|part| {
+        !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    });
+
+    if valid {
+        Ok(())
+    } else {
+        Err(RouxError::InvalidArgument(format!(
+            "not a subreddit name: {:?}",
+            name
+        )))
+    }
+}
+
+/// The error for a post Reddit answered with an empty listing, as it does for IDs that
+/// don't exist instead of responding with `404`.
+fn not_found() -> RouxError {
+    RouxError::RedditError {
+        error: 404,
+        message: "Not Found".to_owned(),
+        reason: None,
+    }
+}
+
+/// Turns a `403` status into `RouxError::Forbidden`, for requests only some users (such as
+/// moderators) can make.
+fn forbidden<T>(result: Result<T, RouxError>) -> Result<T, RouxError> {
+    match result {
+        Err(RouxError::Status(response)) if response.status() == StatusCode::FORBIDDEN => {
+            Err(RouxError::Forbidden)
+        }
+        result => result,
+    }
+}
+
+/// Prefixes `id` with the `kind` of thing it belongs to, unless it already is.
+fn fullname(kind: &str, id: &str) -> String {
+    let prefix = format!("{}_", kind);
+
+    if id.starts_with(&prefix) {
+        id.to_owned()
+    } else {
+        format!("{}{}", prefix, id)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Subreddit;
+    use super::responses::submissions::tests::submission_json;
+    use super::responses::{Submissions, SubredditComments};
+    use super::{Subreddit, SubredditBuilder};
+    use crate::util::transport::tests::{response, MockTransport};
+    use crate::util::{
+        BanOptions, CommentSort, FeedOption, RouxError, SearchOptions, SearchSort, SearchType,
+        TimeFilter,
+    };
+    use futures::StreamExt;
+    use reqwest::StatusCode;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+    use std::time::Duration;
+
     use tokio;
 
+    static USER_AGENT: &str = "macos:roux:v0.3.0 (by /u/beanpup_py)";
+
     #[tokio::test]
     async fn test_no_auth() {
         let subreddit = Subreddit::new("astolfo");
 
         // Test moderators
-        let moderators = subreddit.moderators().await;
+        let moderators = subreddit.moderators(None).await;
         assert!(moderators.is_ok());
 
+        if let Some(after) = moderators.unwrap().data.after {
+            let next = subreddit
+                .moderators(Some(FeedOption::new().after(&after)))
+                .await;
+            assert!(next.is_ok());
+        }
+
         // Test feeds
         let hot = subreddit.hot(25, None).await;
         assert!(hot.is_ok());
@@ -257,11 +1060,556 @@ mod tests {
         let top = subreddit.top(25, None).await;
         assert!(top.is_ok());
 
+        let options = FeedOption::new().timefilter(TimeFilter::Year);
+        let top_year = subreddit.top(25, Some(options)).await;
+        assert!(top_year.is_ok());
+
+        let controversial = subreddit.controversial(25, None).await;
+        assert!(controversial.is_ok());
+
+        let gilded = subreddit.gilded(25, None).await;
+        assert!(gilded.is_ok());
+
+        let best = subreddit.best(25, None).await;
+        assert!(best.is_ok());
+
+        let help = subreddit.hot_by_flair(10, "Help Wanted").await.unwrap();
+        assert!(help
+            .data
+            .children
+            .iter()
+            .all(|post| post.data.link_flair_text.as_deref() == Some("Help Wanted")));
+
+        let emojis = subreddit.emojis().await;
+        assert!(emojis.is_ok());
+
+        let raw = subreddit.feed_raw("hot", 5, None).await.unwrap();
+        assert!(raw["data"]["children"][0]["data"]["id"].is_string());
+
+        let options = SearchOptions::new().sort(SearchSort::New).limit(10);
+        let search = subreddit.search("cosplay & #art", Some(options)).await;
+        assert!(search.is_ok());
+
+        let options = SearchOptions::new()
+            .restrict_sr(false)
+            .search_type(SearchType::Subreddit);
+        let search = subreddit.search_raw("rust", Some(options)).await.unwrap();
+        assert_eq!(search["data"]["children"][0]["kind"], "t5");
+
         let latest_comments = subreddit.latest_comments(None, Some(25)).await;
         assert!(latest_comments.is_ok());
 
         let article_id = &hot.unwrap().data.children.first().unwrap().data.id.clone();
-        let article_comments = subreddit.article_comments(article_id, None, Some(25)).await;
+
+        let submission = subreddit.submission(article_id).await;
+        assert_eq!(&submission.unwrap().id, article_id);
+
+        let url = format!("https://redd.it/{}", article_id);
+        let submission = subreddit.submission_from_url(&url).await;
+        assert_eq!(&submission.unwrap().id, article_id);
+
+        let submission = subreddit
+            .submission_from_url("https://www.reddit.com/r/astolfo")
+            .await;
+        assert!(matches!(submission, Err(RouxError::InvalidArgument(_))));
+
+        let ids = [&article_id[..], &format!("t3_{}", article_id)];
+        let submissions = subreddit.submissions(&ids).await.unwrap();
+        assert!(submissions
+            .data
+            .children
+            .iter()
+            .all(|submission| &submission.data.id == article_id));
+        assert!(subreddit.submissions(&[]).await.unwrap().is_empty());
+
+        let submission = subreddit.submission(&format!("t3_{}", article_id)).await;
+        let submission = submission.unwrap();
+        assert_eq!(&submission.id, article_id);
+        let (article, _) = subreddit
+            .article_with_comments(article_id, None, Some(5), None)
+            .await
+            .unwrap();
+        assert_eq!(&article.id, article_id);
+
+        let depth = subreddit
+            .article_comments(article_id, Some(1), None, None)
+            .await;
+        assert!(depth.unwrap().flatten().iter().all(|c| c.depth == Some(0)));
+
+        let duplicates = subreddit.duplicates(article_id).await;
+        assert!(duplicates.is_ok());
+
+        let article_comments = subreddit
+            .article_comments(article_id, None, Some(25), None)
+            .await;
         assert!(article_comments.is_ok());
+
+        let article_comments = article_comments.unwrap();
+        let num_comments = article_comments.flatten().len();
+        assert!(num_comments <= submission.num_comments as usize);
+
+        let reply = article_comments
+            .flatten()
+            .into_iter()
+            .find(|comment| comment.depth == Some(1))
+            .map(|comment| (comment.id.clone(), comment.parent_id.clone()));
+
+        if let Some((id, parent_id)) = reply {
+            let comment = subreddit
+                .comment(article_id, id.as_deref().unwrap(), Some(1), Some(0))
+                .await
+                .unwrap();
+            let comments = comment.flatten();
+
+            assert_eq!(comments[0].name, parent_id);
+            assert_eq!(comments[1].id, id);
+        }
+
+        let more = article_comments
+            .data
+            .children
+            .into_iter()
+            .find(|comment| comment.kind == "more");
+
+        if let Some(more) = more {
+            let children = more.data.children.unwrap();
+            let more_children = subreddit.more_children(article_id, &children).await;
+            assert!(more_children.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_raw_json() {
+        let subreddit = Subreddit::new("rust");
+
+        let options = SearchOptions::new().limit(25);
+        let search = subreddit.search("title:&", Some(options)).await.unwrap();
+
+        let titles: Vec<_> = search.data.children.iter().map(|c| &c.data.title).collect();
+        assert!(titles.iter().any(|title| title.contains('&')));
+        assert!(titles.iter().all(|title| !title.contains("&amp;")));
+    }
+
+    #[tokio::test]
+    async fn test_sticky() {
+        let subreddit = Subreddit::new("rust");
+
+        let sticky = subreddit.sticky(1).await;
+        assert!(sticky.unwrap().stickied);
+
+        let sticky = subreddit.sticky(3).await;
+        assert!(matches!(sticky, Err(RouxError::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_about() {
+        let subreddit = Subreddit::new_with_user_agent("rust", USER_AGENT);
+
+        let about = subreddit.about().await;
+        assert!(about.unwrap().subscribers > 0);
+
+        let rules = subreddit.rules().await;
+        assert!(!rules.unwrap().rules.is_empty());
+
+        let rate_limit = subreddit.last_rate_limit();
+        assert!(rate_limit.unwrap().used > 0);
+    }
+
+    #[tokio::test]
+    async fn test_paged() {
+        let subreddit = Subreddit::new("rust");
+
+        let hot: Vec<_> = subreddit.hot_paged(25, None).take(60).collect().await;
+        assert_eq!(hot.len(), 60);
+
+        let names: HashSet<_> = hot.into_iter().map(|s| s.unwrap().name).collect();
+        assert_eq!(names.len(), 60);
+    }
+
+    #[tokio::test]
+    async fn test_latest_since() {
+        let subreddit = Subreddit::new("astolfo");
+
+        let latest = subreddit.latest(25, None).await.unwrap();
+        let names: Vec<_> = latest.data.children.iter().map(|c| &c.data.name).collect();
+
+        let since: Vec<_> = subreddit.latest_since(names[10], 25).collect().await;
+        let since: Vec<_> = since.into_iter().map(|s| s.unwrap().name).collect();
+
+        // Oldest first, ending with the newest post (unless one was just posted).
+        let mut newer: Vec<_> = names[..10].iter().map(|name| name.to_string()).collect();
+        newer.reverse();
+        assert!(since.ends_with(&newer));
+    }
+
+    #[tokio::test]
+    async fn test_stream_submissions() {
+        let subreddit = Subreddit::new("astolfo");
+
+        let stream = subreddit.stream_submissions(Duration::from_secs(5));
+        futures::pin_mut!(stream);
+
+        let submission = stream.next().await;
+        assert!(submission.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stream_comments() {
+        let subreddit = Subreddit::new("AskReddit");
+
+        let stream = subreddit.stream_comments(Duration::from_secs(5));
+        futures::pin_mut!(stream);
+
+        let comment = stream.next().await.unwrap().unwrap();
+        let next = stream.next().await.unwrap().unwrap();
+        assert_ne!(comment.name, next.name);
+    }
+
+    #[tokio::test]
+    async fn test_unauthenticated() {
+        let subreddit = Subreddit::new("rust");
+
+        let submitted = subreddit.submit_self("title", "text").await;
+        assert!(matches!(submitted, Err(RouxError::Unauthenticated)));
+
+        let submitted = subreddit
+            .submit_link("title", "https://www.rust-lang.org", false)
+            .await;
+        assert!(matches!(submitted, Err(RouxError::Unauthenticated)));
+
+        let submitted = subreddit.submit_crosspost("title", "t3_abc123").await;
+        assert!(matches!(submitted, Err(RouxError::Unauthenticated)));
+
+        let modqueue = subreddit.modqueue(None).await;
+        assert!(matches!(modqueue, Err(RouxError::Unauthenticated)));
+
+        let unmoderated = subreddit.unmoderated(None).await;
+        assert!(matches!(unmoderated, Err(RouxError::Unauthenticated)));
+
+        let banned = subreddit.ban_user("spammer", BanOptions::new()).await;
+        assert!(matches!(banned, Err(RouxError::Unauthenticated)));
+
+        let optin = subreddit.quarantine_optin().await;
+        assert!(matches!(optin, Err(RouxError::Unauthenticated)));
+
+        let flair = subreddit.set_user_flair("ferris", "Crab", None).await;
+        assert!(matches!(flair, Err(RouxError::Unauthenticated)));
+
+        let flair = subreddit
+            .set_user_flair("ferris", &"a".repeat(65), None)
+            .await;
+        assert!(matches!(flair, Err(RouxError::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_comment_feed_shapes() {
+        let listing = r#"{"kind": "Listing", "data": {"children": [
+            {"kind": "t1", "data": {"id": "a", "body": "comments/", "replies": ""}}
+        ]}}"#;
+
+        let latest: SubredditComments = serde_json::from_str(listing).unwrap();
+        assert_eq!(latest.data.children[0].data.id.as_deref(), Some("a"));
+
+        let article = format!(
+            r#"[{{"kind": "Listing", "data": {{"children": []}}}}, {}]"#,
+            listing
+        );
+        let (_, comments): (Submissions, SubredditComments) =
+            serde_json::from_str(&article).unwrap();
+        assert_eq!(comments.data.children[0].data.id.as_deref(), Some("a"));
+
+        assert!(serde_json::from_str::<SubredditComments>(&article).is_err());
+    }
+
+    #[test]
+    fn test_base_url() {
+        let subreddit = Subreddit::builder("rust")
+            .base_url("http://localhost:8080/")
+            .build();
+
+        assert_eq!(subreddit.url, "http://localhost:8080/r/rust");
+
+        let proxy = reqwest::Proxy::all("http://localhost:3128").unwrap();
+        let subreddit = Subreddit::builder("rust").proxy(proxy).build();
+        assert_eq!(subreddit.url, "https://www.reddit.com/r/rust");
+    }
+
+    #[test]
+    fn test_invalid_user_agent() {
+        let subreddit = Subreddit::builder("rust")
+            .user_agent("roux\n(by /u/beanpup_py)")
+            .try_build();
+        assert!(matches!(subreddit, Err(RouxError::Network(_))));
+
+        assert!(Subreddit::builder("rust")
+            .user_agent(USER_AGENT)
+            .try_build()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_try_new() {
+        assert!(Subreddit::try_new("rust").is_ok());
+        assert!(Subreddit::try_new("learn_rust").is_ok());
+        assert!(Subreddit::try_new("rust+golang").is_ok());
+
+        for name in &[
+            "",
+            "r/rust",
+            "learn rust",
+            "rust+",
+            "rust/hot",
+            "rust?sort=new",
+        ] {
+            assert!(
+                matches!(Subreddit::try_new(name), Err(RouxError::InvalidArgument(_))),
+                "{:?} should be invalid",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_from() {
+        let subreddit = Subreddit::from("rust");
+        assert_eq!(subreddit.url, "https://www.reddit.com/r/rust");
+
+        let subreddit = Subreddit::builder("golang").name("rust").build();
+        assert_eq!(subreddit.name, "rust");
+
+        let subreddit = SubredditBuilder::from_client(reqwest::Client::new(), "rust").build();
+        assert_eq!(subreddit.url, "https://www.reddit.com/r/rust");
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync + Clone>() {}
+        assert_send_sync::<Subreddit>();
+
+        let subreddit = Subreddit::new("rust").with_cache();
+        let clone = subreddit.clone();
+        assert_eq!(clone.url, subreddit.url);
+        assert!(Arc::ptr_eq(
+            clone.sender.cache.as_ref().unwrap(),
+            subreddit.sender.cache.as_ref().unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_allow_nsfw() {
+        assert!(!Subreddit::new("rust").sender.allow_nsfw);
+        assert!(
+            Subreddit::builder("rust")
+                .allow_nsfw()
+                .build()
+                .sender
+                .allow_nsfw
+        );
+    }
+
+    #[test]
+    fn test_cache() {
+        assert!(Subreddit::new("rust").sender.cache.is_none());
+        assert!(Subreddit::builder("rust")
+            .cache()
+            .build()
+            .sender
+            .cache
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_transport() {
+        let listing = serde_json::json!({
+            "kind": "Listing",
+            "data": {"after": "t3_abc", "children": [{"kind": "t3", "data": submission_json(serde_json::json!({}))}]}
+        });
+        let transport = Arc::new(MockTransport::json(200, &listing.to_string()));
+        let subreddit = Subreddit::new("rust").with_transport(transport.clone());
+
+        let hot = subreddit.hot(25, None).await.unwrap();
+        assert_eq!(hot.data.children[0].data.name, "t3_abc");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].url().path(), "/r/rust/hot.json");
+        assert!(requests[0].url().query().unwrap().contains("limit=25"));
+        assert!(requests[0].url().query().unwrap().contains("raw_json=1"));
+    }
+
+    #[tokio::test]
+    async fn test_transport_status() {
+        let subreddit = Subreddit::new("lounge").with_transport(MockTransport::json(
+            403,
+            r#"{"reason": "private", "message": "Forbidden", "error": 403}"#,
+        ));
+
+        match subreddit.hot(25, None).await {
+            Err(RouxError::Status(response)) => {
+                assert_eq!(response.status(), StatusCode::FORBIDDEN)
+            }
+            _ => panic!("expected a status error for a private subreddit"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_not_found() {
+        let subreddit = Subreddit::new("rust").with_transport(MockTransport::json(
+            200,
+            r#"{"kind": "Listing", "data": {"children": []}}"#,
+        ));
+
+        assert!(matches!(
+            subreddit.submission("zzzzzz").await,
+            Err(RouxError::RedditError { error: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_wiki_forbidden() {
+        let subreddit = Subreddit::new("rust").with_transport(MockTransport::json(
+            403,
+            r#"{"reason": "WIKI_DISABLED", "message": "Forbidden", "error": 403}"#,
+        ));
+
+        assert!(matches!(
+            subreddit.wiki_page("config/automoderator").await,
+            Err(RouxError::Forbidden)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_transport_retries() {
+        let mut limited = response(429, "");
+        limited
+            .headers_mut()
+            .insert("retry-after", "0".parse().unwrap());
+        let empty = response(200, r#"{"kind": "Listing", "data": {"children": []}}"#);
+
+        let transport = Arc::new(MockTransport::new(vec![limited, empty]));
+        let subreddit = Subreddit::builder("rust")
+            .transport(transport.clone())
+            .max_retries(1)
+            .build();
+
+        assert!(subreddit.hot(25, None).await.unwrap().is_empty());
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_transport_not_modified() {
+        let body = r#"{"kind": "Listing", "data": {"children": []}}"#;
+        let mut first = response(200, body);
+        first
+            .headers_mut()
+            .insert("etag", "\"abc\"".parse().unwrap());
+        let not_modified = response(304, "");
+
+        let transport = Arc::new(MockTransport::new(vec![first, not_modified]));
+        let subreddit = Subreddit::new("rust")
+            .with_cache()
+            .with_transport(transport.clone());
+
+        assert!(subreddit.hot(25, None).await.unwrap().is_empty());
+        assert!(subreddit.hot(25, None).await.unwrap().is_empty());
+
+        // Other URLs aren't cached yet, so a `304` for them has no body to reuse.
+        assert!(subreddit.rising(25, None).await.is_err());
+
+        let requests = transport.requests();
+        assert!(requests[0].headers().get("if-none-match").is_none());
+        assert_eq!(requests[1].headers()["if-none-match"], "\"abc\"");
+        assert!(requests[2].headers().get("if-none-match").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_comment_sort() {
+        let subreddit = Subreddit::new("AskReddit");
+
+        let top = subreddit.top(1, None).await.unwrap();
+        let article_id = &top.data.children[0].data.id;
+
+        let first_comment = |comments: SubredditComments| {
+            comments
+                .data
+                .children
+                .into_iter()
+                .find(|comment| comment.data.stickied != Some(true))
+                .and_then(|comment| comment.data.id)
+        };
+
+        let new = subreddit
+            .article_comments(article_id, Some(1), Some(25), Some(CommentSort::New))
+            .await
+            .unwrap();
+        let top = subreddit
+            .article_comments(article_id, Some(1), Some(25), Some(CommentSort::Top))
+            .await
+            .unwrap();
+
+        assert_ne!(first_comment(new), first_comment(top));
+    }
+
+    #[tokio::test]
+    async fn test_wiki() {
+        let subreddit = Subreddit::new_with_user_agent("rust", USER_AGENT);
+
+        let pages = subreddit.wiki_pages().await.unwrap();
+        assert!(!pages.is_empty());
+
+        let page = subreddit.wiki_page(&pages[0]).await;
+        assert!(page.is_ok());
+
+        // Only moderators can read the config pages.
+        assert!(matches!(
+            subreddit.wiki_page("config/automoderator").await,
+            Err(RouxError::Forbidden)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_combined() {
+        let subreddit = Subreddit::combined(&["rust", "golang"]);
+        assert_eq!(subreddit.url, "https://www.reddit.com/r/rust+golang");
+
+        let latest = subreddit.latest(100, None).await.unwrap();
+        let subreddits: HashSet<_> = latest
+            .data
+            .children
+            .iter()
+            .map(|c| c.data.subreddit.to_lowercase())
+            .collect();
+
+        assert!(subreddits.contains("rust"));
+        assert!(subreddits.contains("golang"));
+    }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        let transport = Arc::new(MockTransport::json(
+            200,
+            r#"{"kind": "Listing", "data": {"children": []}}"#,
+        ));
+        let subreddit = Subreddit::builder("rust")
+            .timeout(Duration::from_millis(100))
+            .build()
+            .with_transport(transport.clone());
+
+        subreddit.hot(25, None).await.unwrap();
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].timeout(), Some(&Duration::from_millis(100)));
+    }
+
+    #[tokio::test]
+    async fn test_private_status() {
+        let subreddit = Subreddit::new("lounge");
+
+        match subreddit.hot(25, None).await {
+            Err(RouxError::Status(response)) => {
+                assert_eq!(response.status(), StatusCode::FORBIDDEN)
+            }
+            _ => panic!("expected a status error for a private subreddit"),
+        }
     }
 }
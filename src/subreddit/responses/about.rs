@@ -0,0 +1,39 @@
+//! # Subreddit About Responses
+use crate::responses::{BasicListing, BasicThing};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// AboutData
+#[derive(Debug, Deserialize)]
+pub struct AboutData {
+    /// The full 'Thing ID' of the subreddit, including the leading `t5_`.
+    pub name: String,
+    /// The subreddit name, not including the leading `/r/`.
+    pub display_name: String,
+    /// The title of the subreddit.
+    pub title: String,
+    /// The number of subscribers.
+    pub subscribers: u64,
+    /// The number of users currently browsing the subreddit, if available.
+    pub active_user_count: Option<u64>,
+    /// The short description shown to non-subscribers, in **Markdown** format.
+    pub public_description: String,
+    /// The sidebar text, in **Markdown** format.
+    pub description: Option<String>,
+    /// A timestamp of the time when the subreddit was created, in **UTC**.
+    pub created_utc: f64,
+    /// This is `true` if the subreddit is marked NSFW.
+    pub over18: Option<bool>,
+    /// The type of subreddit: `public`, `private`, `restricted`, `gold_restricted`, `archived`...
+    pub subreddit_type: String,
+    /// Fields this struct doesn't have, by name, as Reddit sent them.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+/// About
+pub type About = BasicThing<AboutData>;
+
+/// SubredditListing
+pub type SubredditListing = BasicListing<AboutData>;
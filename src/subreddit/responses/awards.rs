@@ -0,0 +1,20 @@
+//! # Subreddit Award Responses
+use serde::Deserialize;
+
+/// Awarding
+/// An award given to a post or comment, e.g. Reddit Gold.
+#[derive(Debug, Deserialize)]
+pub struct Awarding {
+    /// The ID of the award.
+    pub id: String,
+    /// The name of the award.
+    pub name: String,
+    /// What the award means.
+    pub description: Option<String>,
+    /// The URL of the award's icon.
+    pub icon_url: String,
+    /// The number of times the award was given.
+    pub count: u32,
+    /// The price of the award in coins.
+    pub coin_price: u32,
+}
@@ -0,0 +1,60 @@
+//! # Subreddit Collection Responses
+use super::Submissions;
+use serde::Deserialize;
+
+/// Collection
+#[derive(Debug, Deserialize)]
+pub struct Collection {
+    /// The ID of the collection.
+    pub collection_id: String,
+    /// The title of the collection.
+    pub title: String,
+    /// The description of the collection.
+    #[serde(default)]
+    pub description: String,
+    /// The fullname of the subreddit the collection belongs to.
+    pub subreddit_id: String,
+    /// The moderator who created the collection.
+    pub author_name: String,
+    /// The fullnames of the posts in the collection, in order.
+    pub link_ids: Vec<String>,
+    /// The posts in the collection, in order.
+    pub sorted_links: Submissions,
+    /// A timestamp of the time when the collection was created, in **UTC**.
+    pub created_at_utc: f64,
+    /// A timestamp of the time when the collection was last changed, in **UTC**.
+    pub last_update_utc: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Collection;
+    use crate::subreddit::responses::submissions::tests::submission_json;
+    use serde_json::json;
+
+    #[test]
+    fn test_collection() {
+        let collection = json!({
+            "collection_id": "b4b1c4a5-0000-0000-0000-000000000000",
+            "title": "Megathreads",
+            "description": "",
+            "subreddit_id": "t5_2s7lj",
+            "author_name": "ferris",
+            "author_id": "t2_abc",
+            "display_layout": null,
+            "permalink": "https://www.reddit.com/r/rust/collection/b4b1c4a5-0000-0000-0000-000000000000",
+            "link_ids": ["t3_b", "t3_a"],
+            "sorted_links": {"kind": "Listing", "data": {"after": null, "before": null, "children": [
+                {"kind": "t3", "data": submission_json(json!({"id": "b", "name": "t3_b"}))},
+                {"kind": "t3", "data": submission_json(json!({"id": "a", "name": "t3_a"}))}
+            ]}},
+            "created_at_utc": 1600000000.0,
+            "last_update_utc": 1600000100.0
+        });
+        let collection: Collection = serde_json::from_value(collection).unwrap();
+
+        assert_eq!(collection.title, "Megathreads");
+        assert_eq!(collection.link_ids, vec!["t3_b", "t3_a"]);
+        assert_eq!(collection.sorted_links.data.children[0].data.id, "b");
+    }
+}
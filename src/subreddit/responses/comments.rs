@@ -1,13 +1,23 @@
 //! # Subreddit Comment Responses
-use crate::responses::BasicListing;
-use serde::Deserialize;
+use super::awards::Awarding;
+use crate::responses::{ApiResponse, BasicListing, BasicThing, Edited};
+use crate::util::defaults::{deserialize_optional_author, DELETED_AUTHOR};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
 
 /// SubredditCommentsData
-/// Everything is an option to deal with both `latest_comments` and `article_comments`
+/// Everything is an option to deal with both `latest_comments` and `article_comments`,
+/// as well as the `more` stubs standing in for comments that weren't loaded.
 #[derive(Debug, Deserialize)]
 pub struct SubredditCommentsData {
     /// Total awards
     pub total_awards_received: Option<i32>,
+    /// The awards the comment received
+    #[serde(default)]
+    pub all_awardings: Vec<Awarding>,
     /// Approved at (UTC)
     pub approved_at_utc: Option<f64>,
     /// Link id
@@ -26,7 +36,9 @@ pub struct SubredditCommentsData {
     pub archived: Option<bool>,
     /// No follow
     pub no_follow: Option<bool>,
-    /// Auuthor
+    /// Auuthor. This is `[deleted]` if the comment or its author's account was deleted,
+    /// see `is_deleted`, and `None` for `more` stubs.
+    #[serde(default, deserialize_with = "deserialize_optional_author")]
     pub author: Option<String>,
     /// Can mod post
     pub can_mod_post: Option<bool>,
@@ -58,7 +70,7 @@ pub struct SubredditCommentsData {
     pub downs: Option<i32>,
     /// Is submitter
     pub is_submitter: Option<bool>,
-    /// HTML
+    /// The body rendered as HTML, unescaped as `Subreddit` requests ask for `raw_json=1`.
     pub body_html: Option<String>,
     /// Distinguished
     pub distinguished: Option<String>,
@@ -74,7 +86,8 @@ pub struct SubredditCommentsData {
     pub author_flair_text_color: Option<String>,
     /// Score hidden
     pub score_hidden: Option<bool>,
-    /// Permalink
+    /// Permalink, relative to `https://www.reddit.com`. See `full_permalink` for the
+    /// absolute URL.
     pub permalink: Option<String>,
     /// Number of reports
     pub num_reports: Option<i32>,
@@ -90,6 +103,9 @@ pub struct SubredditCommentsData {
     pub link_url: Option<String>,
     /// Created
     pub created: Option<f64>,
+    /// Whether, and when, the comment was edited
+    #[serde(default)]
+    pub edited: Edited,
     /// Collapsed
     pub collapsed: Option<bool>,
     /// Controversiality
@@ -103,21 +119,238 @@ pub struct SubredditCommentsData {
     /// UPS?
     pub ups: Option<i32>,
     /// Replies
-    pub replies: Option<SubredditReplies>,
+    #[serde(default)]
+    pub replies: SubredditReplies,
+    /// Number of comments not loaded (`more` stubs only)
+    pub count: Option<i32>,
+    /// Depth in the comment tree
+    pub depth: Option<i32>,
+    /// IDs of the comments not loaded, to pass to `Subreddit::more_children` (`more` stubs only)
+    pub children: Option<Vec<String>>,
+    /// Fields this struct doesn't have, by name, as Reddit sent them.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl SubredditCommentsData {
+    /// This is `true` if the comment or its author's account was deleted.
+    /// Always `false` for `more` stubs, which have no author.
+    pub fn is_deleted(&self) -> bool {
+        self.author.as_deref() == Some(DELETED_AUTHOR)
+    }
+
+    /// The absolute URL of `permalink`. Always `None` for `more` stubs.
+    pub fn full_permalink(&self) -> Option<String> {
+        let permalink = self.permalink.as_ref()?;
+        Some(format!("https://www.reddit.com{}", permalink))
+    }
+
+    /// `created_utc` as a `DateTime<Utc>`.
+    #[cfg(feature = "chrono")]
+    pub fn created_utc_datetime(&self) -> Option<DateTime<Utc>> {
+        self.created_utc.and_then(crate::util::time::from_timestamp)
+    }
 }
 
 /// Reply
-pub type Reply = BasicListing<Box<SubredditCommentsData>>;
+pub type Reply = BasicListing<SubredditCommentsData>;
 
-/// Replies can be more comments or an empty string
-#[derive(Debug, Deserialize)]
-#[serde(untagged)]
+/// MoreData
+/// A `more` stub standing in for replies that weren't loaded.
+#[derive(Debug, Default)]
+pub struct MoreData {
+    /// ID
+    pub id: String,
+    /// Name
+    pub name: String,
+    /// Parent ID
+    pub parent_id: String,
+    /// Number of comments not loaded
+    pub count: i32,
+    /// Depth in the comment tree
+    pub depth: i32,
+    /// IDs of the comments not loaded, to pass to `Subreddit::more_children`
+    pub children: Vec<String>,
+}
+
+/// Replies to a comment
+#[derive(Debug, Default)]
 pub enum SubredditReplies {
-    /// Reply
-    Reply(Reply),
-    /// String
-    Str(String)
+    /// No replies. Reddit sends these as an empty string.
+    #[default]
+    None,
+    /// Replies too deep in the tree to be loaded with the comment.
+    More(MoreData),
+    /// Replies. The listing may end with a `more` stub (`kind == "more"`) if only
+    /// some of them were loaded.
+    Listing(Box<Reply>),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawReplies {
+    Listing(Box<Reply>),
+    // Always empty, it's only here to accept Reddit's `""` for no replies.
+    Str(#[allow(dead_code)] String),
+}
+
+impl<'de> Deserialize<'de> for SubredditReplies {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut listing = match Option::<RawReplies>::deserialize(deserializer)? {
+            Some(RawReplies::Listing(listing)) => listing,
+            Some(RawReplies::Str(_)) | None => return Ok(SubredditReplies::None),
+        };
+
+        let children = &mut listing.data.children;
+
+        if children.len() == 1 && children[0].kind == "more" {
+            let stub = children.pop().unwrap().data;
+
+            return Ok(SubredditReplies::More(MoreData {
+                id: stub.id.unwrap_or_default(),
+                name: stub.name.unwrap_or_default(),
+                parent_id: stub.parent_id.unwrap_or_default(),
+                count: stub.count.unwrap_or_default(),
+                depth: stub.depth.unwrap_or_default(),
+                children: stub.children.unwrap_or_default(),
+            }));
+        }
+
+        Ok(SubredditReplies::Listing(listing))
+    }
 }
 
 /// SubredditComments
 pub type SubredditComments = BasicListing<SubredditCommentsData>;
+
+impl SubredditComments {
+    /// Every comment in the tree in document order (depth-first), skipping `more` stubs.
+    pub fn flatten(&self) -> Vec<&SubredditCommentsData> {
+        let mut comments = Vec::new();
+        // An explicit stack so deep threads can't overflow the call stack.
+        let mut stack: Vec<_> = self.data.children.iter().rev().collect();
+
+        while let Some(comment) = stack.pop() {
+            if comment.kind == "more" {
+                continue;
+            }
+
+            if let SubredditReplies::Listing(replies) = &comment.data.replies {
+                stack.extend(replies.data.children.iter().rev());
+            }
+
+            comments.push(&comment.data);
+        }
+
+        comments
+    }
+}
+
+/// MoreChildrenData
+#[derive(Debug, Deserialize)]
+pub struct MoreChildrenData {
+    /// The loaded comments, and `more` stubs for any still left to load.
+    pub things: Vec<BasicThing<SubredditCommentsData>>,
+}
+
+/// MoreChildren
+pub type MoreChildren = ApiResponse<MoreChildrenData>;
+
+#[cfg(test)]
+mod tests {
+    use super::{SubredditComments, SubredditCommentsData, SubredditReplies};
+
+    #[test]
+    fn test_replies() {
+        let comment: SubredditCommentsData = serde_json::from_str(r#"{"replies": ""}"#).unwrap();
+        assert!(matches!(comment.replies, SubredditReplies::None));
+
+        let comment: SubredditCommentsData = serde_json::from_str(r#"{}"#).unwrap();
+        assert!(matches!(comment.replies, SubredditReplies::None));
+
+        let more = r#"{"replies": {"kind": "Listing", "data": {"children": [{"kind": "more",
+            "data": {"count": 2, "name": "t1_b", "id": "b", "parent_id": "t1_a", "depth": 10,
+            "children": ["b", "c"]}}]}}}"#;
+        let comment: SubredditCommentsData = serde_json::from_str(more).unwrap();
+        match comment.replies {
+            SubredditReplies::More(more) => assert_eq!(more.children, vec!["b", "c"]),
+            _ => panic!("expected more replies"),
+        }
+
+        let listing = r#"{"replies": {"kind": "Listing", "data": {"children": [{"kind": "t1",
+            "data": {"id": "b", "body": "reply", "replies": ""}}]}}}"#;
+        let comment: SubredditCommentsData = serde_json::from_str(listing).unwrap();
+        match comment.replies {
+            SubredditReplies::Listing(listing) => {
+                assert_eq!(listing.data.children[0].data.body.as_deref(), Some("reply"))
+            }
+            _ => panic!("expected a listing of replies"),
+        }
+    }
+
+    #[test]
+    fn test_deleted() {
+        let deleted = r#"{"author": "[deleted]", "body": "[deleted]", "author_fullname": null,
+            "author_flair_text": null, "score": 1, "replies": ""}"#;
+        let deleted: SubredditCommentsData = serde_json::from_str(deleted).unwrap();
+        assert!(deleted.is_deleted());
+
+        // Reddit sometimes sends `null` instead of `[deleted]`.
+        let deleted: SubredditCommentsData =
+            serde_json::from_str(r#"{"author": null, "body": "[removed]"}"#).unwrap();
+        assert!(deleted.is_deleted());
+        assert_eq!(deleted.author.as_deref(), Some("[deleted]"));
+
+        // `more` stubs have no author at all.
+        let more: SubredditCommentsData =
+            serde_json::from_str(r#"{"count": 2, "children": ["b", "c"]}"#).unwrap();
+        assert!(!more.is_deleted());
+        assert_eq!(more.author, None);
+
+        let comment: SubredditCommentsData = serde_json::from_str(r#"{"author": "a"}"#).unwrap();
+        assert!(!comment.is_deleted());
+        assert!(comment.all_awardings.is_empty());
+    }
+
+    #[test]
+    fn test_full_permalink() {
+        let comment = r#"{"permalink": "/r/rust/comments/abc/title/def/"}"#;
+        let comment: SubredditCommentsData = serde_json::from_str(comment).unwrap();
+        assert_eq!(
+            comment.full_permalink().as_deref(),
+            Some("https://www.reddit.com/r/rust/comments/abc/title/def/")
+        );
+
+        let more: SubredditCommentsData = serde_json::from_str(r#"{"count": 2}"#).unwrap();
+        assert_eq!(more.full_permalink(), None);
+    }
+
+    #[test]
+    fn test_extra() {
+        let comment = r#"{"id": "a", "collapsed_reason_code": "LOW_SCORE", "replies": ""}"#;
+        let comment: SubredditCommentsData = serde_json::from_str(comment).unwrap();
+
+        assert_eq!(comment.extra["collapsed_reason_code"], "LOW_SCORE");
+        assert!(!comment.extra.contains_key("id"));
+        assert!(!comment.extra.contains_key("replies"));
+    }
+
+    #[test]
+    fn test_flatten() {
+        let comments = r#"{"kind": "Listing", "data": {"children": [
+            {"kind": "t1", "data": {"id": "a", "replies": {"kind": "Listing", "data": {"children": [
+                {"kind": "t1", "data": {"id": "b", "replies": ""}},
+                {"kind": "more", "data": {"count": 1, "id": "c", "children": ["c"]}}
+            ]}}}},
+            {"kind": "t1", "data": {"id": "d", "replies": ""}}
+        ]}}"#;
+        let comments: SubredditComments = serde_json::from_str(comments).unwrap();
+
+        let ids: Vec<_> = comments
+            .flatten()
+            .iter()
+            .map(|comment| comment.id.as_deref().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["a", "b", "d"]);
+    }
+}
@@ -0,0 +1,44 @@
+//! # Subreddit Emoji Responses
+use serde::Deserialize;
+
+/// Emoji
+#[derive(Debug, Deserialize)]
+pub struct Emoji {
+    /// The URL of the image of the emoji.
+    pub url: String,
+    /// The fullname of the user who added the emoji. Missing for Reddit's own emojis.
+    pub created_by: Option<String>,
+    /// This is `true` if only moderators can use the emoji in flair.
+    #[serde(default)]
+    pub mod_flair_only: bool,
+    /// This is `true` if the emoji can be used in post flair.
+    #[serde(default)]
+    pub post_flair_allowed: bool,
+    /// This is `true` if the emoji can be used in user flair.
+    #[serde(default)]
+    pub user_flair_allowed: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Emoji;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_emojis() {
+        let emojis = r#"{
+            "snoomojis": {"cake": {"url": "https://emoji.redditmedia.com/cake.png",
+                "user_flair_allowed": true, "post_flair_allowed": true, "mod_flair_only": false,
+                "created_by": null}},
+            "t5_2s7lj": {"ferris": {"url": "https://emoji.redditmedia.com/ferris.png",
+                "user_flair_allowed": true, "post_flair_allowed": false, "mod_flair_only": false,
+                "created_by": "t2_abc"}}
+        }"#;
+        let emojis: HashMap<String, HashMap<String, Emoji>> = serde_json::from_str(emojis).unwrap();
+
+        let ferris = &emojis["t5_2s7lj"]["ferris"];
+        assert_eq!(ferris.url, "https://emoji.redditmedia.com/ferris.png");
+        assert!(!ferris.post_flair_allowed);
+        assert_eq!(emojis["snoomojis"]["cake"].created_by, None);
+    }
+}
@@ -0,0 +1,41 @@
+//! # Subreddit Flair Responses
+use serde::Deserialize;
+
+/// FlairTemplate
+#[derive(Debug, Deserialize)]
+pub struct FlairTemplate {
+    /// The ID of the template, to pass to `Subreddit::set_flair`.
+    pub id: String,
+    /// The text of the flair.
+    pub text: String,
+    /// The CSS class of the flair. Can be an empty string.
+    pub css_class: String,
+    /// The background color of the flair as a hex code, e.g. `#ff4500`.
+    /// Can be an empty string, or `transparent`.
+    pub background_color: String,
+    /// The color of the text, `light` or `dark`.
+    pub text_color: String,
+    /// This is `true` if users can edit the text of the flair.
+    pub text_editable: bool,
+    /// This is `true` if only moderators can use the flair.
+    pub mod_only: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FlairTemplate;
+
+    #[test]
+    fn test_flair_templates() {
+        let templates = r##"[{"allowable_content": "all", "text": "Discussion",
+            "text_color": "dark", "mod_only": false, "background_color": "#ffd635",
+            "id": "a1b2c3d4-0000-0000-0000-000000000000", "css_class": "discussion",
+            "max_emojis": 10, "richtext": [], "text_editable": false, "override_css": false,
+            "type": "text"}]"##;
+        let templates: Vec<FlairTemplate> = serde_json::from_str(templates).unwrap();
+
+        assert_eq!(templates[0].text, "Discussion");
+        assert_eq!(templates[0].background_color, "#ffd635");
+        assert!(!templates[0].mod_only);
+    }
+}
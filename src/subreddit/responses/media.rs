@@ -0,0 +1,128 @@
+//! # Subreddit Media Responses
+use serde::Deserialize;
+
+/// Thumbnail of a submission.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "String")]
+pub enum Thumbnail {
+    /// A self post, which has no thumbnail (`self`).
+    SelfPost,
+    /// A link post without a thumbnail of its own (`default`).
+    Default,
+    /// A NSFW post, whose thumbnail is hidden (`nsfw`).
+    Nsfw,
+    /// A spoiler, whose thumbnail is hidden (`spoiler`).
+    Spoiler,
+    /// An image post without a generated thumbnail (`image`).
+    Image,
+    /// No thumbnail at all (an empty string).
+    None,
+    /// URL of the thumbnail.
+    Url(String),
+}
+
+impl From<String> for Thumbnail {
+    fn from(thumbnail: String) -> Self {
+        match &thumbnail[..] {
+            "self" => Thumbnail::SelfPost,
+            "default" => Thumbnail::Default,
+            "nsfw" => Thumbnail::Nsfw,
+            "spoiler" => Thumbnail::Spoiler,
+            "image" => Thumbnail::Image,
+            "" => Thumbnail::None,
+            _ => Thumbnail::Url(thumbnail),
+        }
+    }
+}
+
+/// Preview
+/// Images Reddit generated to preview a submission, e.g. from the linked page.
+#[derive(Debug, Deserialize)]
+pub struct Preview {
+    /// The preview images, usually just one.
+    pub images: Vec<PreviewImage>,
+    /// This is `true` if the preview is shown on the site.
+    pub enabled: bool,
+}
+
+/// PreviewImage
+#[derive(Debug, Deserialize)]
+pub struct PreviewImage {
+    /// The ID of the image.
+    pub id: String,
+    /// The image at its original size.
+    pub source: PreviewSource,
+    /// Smaller versions of the image, smallest first.
+    pub resolutions: Vec<PreviewSource>,
+}
+
+/// PreviewSource
+#[derive(Debug, Deserialize)]
+pub struct PreviewSource {
+    /// URL of the image. Its `&`s are HTML-escaped as `&amp;` unless the request was made
+    /// with `raw_json=1`, as `Subreddit` requests are.
+    pub url: String,
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+}
+
+/// GalleryData
+/// The order of the images in a gallery post.
+#[derive(Debug, Deserialize)]
+pub struct GalleryData {
+    /// The images, in order.
+    pub items: Vec<GalleryItem>,
+}
+
+/// GalleryItem
+#[derive(Debug, Deserialize)]
+pub struct GalleryItem {
+    /// The key of the image in `media_metadata`.
+    pub media_id: String,
+    /// The ID of the item.
+    pub id: u64,
+    /// The caption of the image, if any.
+    pub caption: Option<String>,
+    /// The link attached to the image, if any.
+    pub outbound_url: Option<String>,
+}
+
+/// MediaMetadata
+/// An image uploaded to Reddit, e.g. as part of a gallery.
+#[derive(Debug, Deserialize)]
+pub struct MediaMetadata {
+    /// `valid` once the image has been processed, `unprocessed` or `failed` otherwise.
+    pub status: String,
+    /// Kind of media, e.g. `Image` or `AnimatedImage`.
+    #[serde(rename = "e")]
+    pub kind: Option<String>,
+    /// MIME type, e.g. `image/jpg`.
+    #[serde(rename = "m")]
+    pub mime_type: Option<String>,
+    /// The image at its original size. `None` unless `status` is `valid`.
+    #[serde(rename = "s")]
+    pub source: Option<MediaSource>,
+    /// Smaller versions of the image.
+    #[serde(rename = "p", default)]
+    pub previews: Vec<MediaSource>,
+}
+
+/// MediaSource
+#[derive(Debug, Deserialize)]
+pub struct MediaSource {
+    /// URL of the image. `None` for animated images, which use `gif` and `mp4` instead.
+    #[serde(rename = "u")]
+    pub url: Option<String>,
+    /// URL of the animated image as a GIF.
+    pub gif: Option<String>,
+    /// URL of the animated image as an MP4 video.
+    pub mp4: Option<String>,
+    /// Width in pixels.
+    #[serde(rename = "x")]
+    pub width: u32,
+    /// Height in pixels.
+    #[serde(rename = "y")]
+    pub height: u32,
+}
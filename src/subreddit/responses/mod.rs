@@ -1,9 +1,48 @@
 //! # Subreddit Responses
 pub mod moderators;
-pub use moderators::{Moderators, ModeratorsData};
+pub use moderators::{ModPermission, Moderators, ModeratorsData};
+
+pub mod awards;
+pub use awards::Awarding;
+
+pub mod media;
+pub use media::{
+    GalleryData, GalleryItem, MediaMetadata, MediaSource, Preview, PreviewImage, PreviewSource,
+    Thumbnail,
+};
+
+pub mod poll;
+pub use poll::{PollData, PollOption};
 
 pub mod submissions;
 pub use submissions::{Submissions, SubmissionsData};
 
 pub mod comments;
-pub use comments::{SubredditComments, SubredditCommentsData};
+pub use comments::{
+    MoreChildren, MoreChildrenData, MoreData, SubredditComments, SubredditCommentsData,
+    SubredditReplies,
+};
+
+pub mod about;
+pub use about::{About, AboutData, SubredditListing};
+
+pub mod rules;
+pub use rules::{Rule, Rules};
+
+pub mod submit;
+pub use submit::{Submitted, SubmittedPost};
+
+pub mod wiki;
+pub use wiki::{Wiki, WikiPage, WikiPages, WikiRevisionAuthor};
+
+pub mod flair;
+pub use flair::FlairTemplate;
+
+pub mod collection;
+pub use collection::Collection;
+
+pub mod emoji;
+pub use emoji::Emoji;
+
+pub mod traffic;
+pub use traffic::{Traffic, TrafficPoint};
@@ -2,6 +2,33 @@
 use crate::responses::{BasicThing, Listing};
 use serde::Deserialize;
 
+/// A permission granted to a moderator.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModPermission {
+    /// Full permissions, including every other one.
+    All,
+    /// Manage approved users and banned users.
+    Access,
+    /// Manage chat settings.
+    ChatConfig,
+    /// Moderate chat.
+    ChatOperator,
+    /// Manage subreddit settings.
+    Config,
+    /// Manage user and post flair.
+    Flair,
+    /// Read and reply to modmail.
+    Mail,
+    /// Approve, remove, lock and distinguish posts and comments.
+    Posts,
+    /// Manage the wiki.
+    Wiki,
+    /// A permission this version of roux doesn't know about.
+    #[serde(other)]
+    Unknown,
+}
+
 /// ModeratorsData
 #[derive(Debug, Deserialize)]
 pub struct ModeratorsData {
@@ -11,7 +38,50 @@ pub struct ModeratorsData {
     pub name: String,
     /// Author flair text
     pub author_flair_text: Option<String>,
+    /// Permissions of the moderator
+    #[serde(default, rename = "mod_permissions")]
+    pub permissions: Vec<ModPermission>,
+}
+
+impl ModeratorsData {
+    /// Whether the moderator has `permission`, either directly or through `ModPermission::All`.
+    pub fn has_permission(&self, permission: ModPermission) -> bool {
+        self.permissions
+            .iter()
+            .any(|p| *p == permission || *p == ModPermission::All)
+    }
 }
 
 /// Moderators
+/// `data.after` is set when there is another page of moderators.
 pub type Moderators = BasicThing<Listing<ModeratorsData>>;
+
+#[cfg(test)]
+mod tests {
+    use super::{ModPermission, ModeratorsData};
+
+    #[test]
+    fn test_permissions() {
+        let moderator = r#"{"id": "t2_a", "name": "a", "author_flair_text": null,
+            "mod_permissions": ["posts", "flair", "chat_config", "something_new"]}"#;
+        let moderator: ModeratorsData = serde_json::from_str(moderator).unwrap();
+
+        assert_eq!(
+            moderator.permissions,
+            vec![
+                ModPermission::Posts,
+                ModPermission::Flair,
+                ModPermission::ChatConfig,
+                ModPermission::Unknown
+            ]
+        );
+        assert!(moderator.has_permission(ModPermission::Flair));
+        assert!(!moderator.has_permission(ModPermission::Config));
+
+        let moderator = r#"{"id": "t2_b", "name": "b", "mod_permissions": ["all"]}"#;
+        let moderator: ModeratorsData = serde_json::from_str(moderator).unwrap();
+
+        assert_eq!(moderator.permissions, vec![ModPermission::All]);
+        assert!(moderator.has_permission(ModPermission::Config));
+    }
+}
@@ -0,0 +1,27 @@
+//! # Subreddit Poll Responses
+use serde::Deserialize;
+
+/// PollData
+#[derive(Debug, Deserialize)]
+pub struct PollData {
+    /// The options to vote for.
+    pub options: Vec<PollOption>,
+    /// The number of votes on the poll.
+    pub total_vote_count: u64,
+    /// A timestamp of the time when voting ends, in **milliseconds** since the epoch.
+    pub voting_end_timestamp: u64,
+    /// The ID of the option the logged-in user voted for, if any.
+    pub user_selection: Option<String>,
+}
+
+/// PollOption
+#[derive(Debug, Deserialize)]
+pub struct PollOption {
+    /// The ID of the option.
+    pub id: String,
+    /// The text of the option.
+    pub text: String,
+    /// The number of votes for this option. Reddit only shows this once voting has ended,
+    /// or to users who voted.
+    pub vote_count: Option<u64>,
+}
@@ -0,0 +1,28 @@
+//! # Subreddit Rules Responses
+use serde::Deserialize;
+
+/// Rule
+#[derive(Debug, Deserialize)]
+pub struct Rule {
+    /// The rule's title.
+    pub short_name: String,
+    /// The rule's details, in **Markdown** format.
+    pub description: String,
+    /// What the rule applies to: `link`, `comment` or `all`.
+    pub kind: String,
+    /// The reason shown when reporting content that breaks this rule.
+    pub violation_reason: String,
+    /// Position of the rule in the list, starting at 0.
+    pub priority: u32,
+    /// A timestamp of the time when the rule was created, in **UTC**.
+    pub created_utc: f64,
+}
+
+/// Rules
+#[derive(Debug, Deserialize)]
+pub struct Rules {
+    /// The subreddit's rules.
+    pub rules: Vec<Rule>,
+    /// Reddit's site-wide rules, which apply to every subreddit.
+    pub site_rules: Vec<String>,
+}
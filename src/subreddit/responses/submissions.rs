@@ -1,5 +1,13 @@
 //! # Subreddit Submussion Responses
-use crate::responses::BasicListing;
+use std::collections::HashMap;
+
+use super::awards::Awarding;
+use super::media::{GalleryData, MediaMetadata, Preview, Thumbnail};
+use super::poll::PollData;
+use crate::responses::{BasicListing, Edited};
+use crate::util::defaults::{deserialize_author, DELETED_AUTHOR};
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -16,6 +24,9 @@ pub struct SubmissionsData {
     /// The subreddit that this submission was posted in (not including `/r/`)
     pub subreddit: String,
     /// If this is a self post, it contains the HTML of the post body. Otherwise, it is `None`.
+    ///
+    /// `Subreddit` requests ask for `raw_json=1`, so this is plain HTML. Elsewhere Reddit
+    /// escapes it again (`&lt;div&gt;` instead of `<div>`).
     pub selftext_html: Option<String>,
     /// The self text in **Markdown** format, if this is a self post. Unlike `selftext_html`, this
     /// is an **empty string** if this is a link post.
@@ -42,13 +53,22 @@ pub struct SubmissionsData {
     // skipped from_kind
     /// The amount of times that a user has been gilded (gifted Reddit Gold).
     pub gilded: u64,
+    /// The number of awards the submission received.
+    #[serde(default)]
+    pub total_awards_received: u32,
+    /// The awards the submission received. Empty for content older than awards.
+    #[serde(default)]
+    pub all_awardings: Vec<Awarding>,
     /// This is `true` if Reddit has archived the submission (usually done after 6 months).
     /// Archived submissions cannot be voted or commented upon.
     pub archived: bool,
     /// This is `true` if the logged-in user has already followed this link, otherwise `false`.
     pub clicked: bool,
     // skipped report_reasons
-    /// The name of the author of the submission (not including the leading `/u/`)
+    /// The name of the author of the submission (not including the leading `/u/`).
+    /// This is `[deleted]` if the submission or its author's account was deleted,
+    /// see `is_deleted`.
+    #[serde(deserialize_with = "deserialize_author")]
     pub author: String,
     // skipped media
     /// The overall points score of this post, as shown on the upvote counter. This is the
@@ -62,19 +82,20 @@ pub struct SubmissionsData {
     pub over_18: bool,
     /// This is `true` if the logged-in user has clicked 'hide' on this post.
     pub hidden: bool,
-    // TODO: skipped preview
+    /// Preview images of the submission, if Reddit generated any.
+    pub preview: Option<Preview>,
     /// The number of comment replies to this submission.
     pub num_comments: u64,
-    /// The URL to the link thumbnail. This is "self" if this is a self post, or "default" if
-    /// a thumbnail is not available.
-    pub thumbnail: String,
+    /// The link thumbnail. This is `Thumbnail::SelfPost` if this is a self post, or
+    /// `Thumbnail::Default` if a thumbnail is not available.
+    pub thumbnail: Thumbnail,
     /// The Reddit ID for the subreddit where this was posted, **including the leading `t5_`**.
     pub subreddit_id: String,
-    /// This is `true` if the score is being hidden.
+    /// This is `true` if the score is being hidden, as it is for a while after posting in
+    /// some subreddits. Comments call this `score_hidden`.
     pub hide_score: bool,
-    /// This is `false` if the submission is not edited and is the edit timestamp if it is edited.
-    /// Access through the functions of `Submission` instead.
-    pub edited: Value,
+    /// Whether, and when, the submission was edited.
+    pub edited: Edited,
     /// The CSS class set for the link's flair (if available), otherwise `None`.
     pub link_flair_css_class: Option<String>,
     /// The CSS class set for the author's flair (if available). If there is no flair, this is
@@ -97,7 +118,8 @@ pub struct SubmissionsData {
     /// This is `true` if this is a self post.
     pub is_self: bool,
     // TODO: skipped from_id
-    /// The permanent, long link for this submission.
+    /// The permanent, long link for this submission, relative to `https://www.reddit.com`.
+    /// See `full_permalink` for the absolute URL.
     pub permalink: String,
     /// This is `true` if the submission has been locked by a moderator, and no replies can be
     /// made.
@@ -131,7 +153,288 @@ pub struct SubmissionsData {
     pub visited: bool,
     /// The number of reports, if the user is a moderator of this subreddit.
     pub num_reports: Option<u64>,
+    /// This is `true` if this is a gallery post.
+    #[serde(default)]
+    pub is_gallery: bool,
+    /// The images uploaded with the post, by media ID. See `gallery_urls` for them in order.
+    pub media_metadata: Option<HashMap<String, MediaMetadata>>,
+    /// The order of the images of a gallery post.
+    pub gallery_data: Option<GalleryData>,
+    /// The poll of a poll post.
+    pub poll_data: Option<PollData>,
+    /// The fullname of the original submission, if this is a crosspost.
+    pub crosspost_parent: Option<String>,
+    /// The original submission, if this is a crosspost. Reddit sends a list, but it only
+    /// ever has the one submission.
+    pub crosspost_parent_list: Option<Vec<SubmissionsData>>,
+    /// Fields this struct doesn't have, by name, as Reddit sent them.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl SubmissionsData {
+    /// This is `true` if the submission or its author's account was deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.author == DELETED_AUTHOR
+    }
+
+    /// The absolute URL of `permalink`.
+    pub fn full_permalink(&self) -> String {
+        format!("https://www.reddit.com{}", self.permalink)
+    }
+
+    /// URLs of the images of a gallery post, in order. Empty if this isn't a gallery post.
+    /// Images that failed to process are skipped.
+    pub fn gallery_urls(&self) -> Vec<&str> {
+        let (metadata, gallery) = match (&self.media_metadata, &self.gallery_data) {
+            (Some(metadata), Some(gallery)) => (metadata, gallery),
+            _ => return Vec::new(),
+        };
+
+        gallery
+            .items
+            .iter()
+            .filter_map(|item| metadata.get(&item.media_id)?.source.as_ref())
+            .filter_map(|source| source.url.as_deref().or(source.gif.as_deref()))
+            .collect()
+    }
+
+    /// `created_utc` as a `DateTime<Utc>`.
+    #[cfg(feature = "chrono")]
+    pub fn created_utc_datetime(&self) -> Option<DateTime<Utc>> {
+        crate::util::time::from_timestamp(self.created_utc)
+    }
 }
 
 /// Submissions
 pub type Submissions = BasicListing<SubmissionsData>;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::SubmissionsData;
+    use crate::subreddit::responses::Thumbnail;
+    use serde_json::{json, Value};
+
+    /// A self post, with `fields` added or replaced.
+    pub(crate) fn submission(fields: Value) -> SubmissionsData {
+        serde_json::from_value(submission_json(fields)).unwrap()
+    }
+
+    /// JSON of a self post, with `fields` added or replaced.
+    pub(crate) fn submission_json(fields: Value) -> Value {
+        let mut submission = json!({
+            "domain": "self.rust", "subreddit": "rust", "selftext": "", "id": "abc",
+            "gilded": 0, "archived": false, "clicked": false, "author": "ferris", "score": 1.0,
+            "over_18": false, "hidden": false, "num_comments": 0, "thumbnail": "self",
+            "subreddit_id": "t5_2s7lj", "hide_score": false, "edited": false, "downs": 0.0,
+            "ups": 1.0, "saved": false, "stickied": false, "is_self": true,
+            "permalink": "/r/rust/comments/abc/title/", "locked": false, "name": "t3_abc",
+            "created": 1600000000.0, "quarantine": false, "title": "title",
+            "created_utc": 1600000000.0, "visited": false,
+        });
+
+        for (key, value) in fields.as_object().unwrap() {
+            submission[key] = value.clone();
+        }
+
+        submission
+    }
+
+    #[test]
+    fn test_extra() {
+        let post = submission(json!({"upvote_ratio": 0.97, "removed_by_category": null}));
+
+        assert_eq!(post.extra["upvote_ratio"], json!(0.97));
+        assert_eq!(post.extra["removed_by_category"], Value::Null);
+        assert!(!post.extra.contains_key("title"));
+    }
+
+    #[test]
+    fn test_selftext_html() {
+        let post = submission(json!({
+            "selftext": "**hi**",
+            "selftext_html": "<div class=\"md\"><p><strong>hi</strong></p></div>",
+        }));
+        assert_eq!(
+            post.selftext_html.as_deref(),
+            Some("<div class=\"md\"><p><strong>hi</strong></p></div>")
+        );
+
+        let link = submission(json!({"is_self": false, "selftext_html": null}));
+        assert_eq!(link.selftext_html, None);
+    }
+
+    #[test]
+    fn test_gallery_urls() {
+        let gallery = submission(json!({
+            "is_gallery": true,
+            "gallery_data": {"items": [
+                {"media_id": "b", "id": 2},
+                {"media_id": "a", "id": 1, "caption": "first"},
+                {"media_id": "c", "id": 3}
+            ]},
+            "media_metadata": {
+                "a": {"status": "valid", "e": "Image", "m": "image/jpg",
+                    "s": {"u": "https://i.redd.it/a.jpg", "x": 640, "y": 480},
+                    "p": [{"u": "https://preview.redd.it/a.jpg?width=108", "x": 108, "y": 81}]},
+                "b": {"status": "valid", "e": "AnimatedImage", "m": "image/gif",
+                    "s": {"gif": "https://i.redd.it/b.gif", "mp4": "https://i.redd.it/b.mp4",
+                        "x": 320, "y": 240}},
+                "c": {"status": "failed"}
+            }
+        }));
+
+        assert!(gallery.is_gallery);
+        assert_eq!(
+            gallery.gallery_urls(),
+            vec!["https://i.redd.it/b.gif", "https://i.redd.it/a.jpg"]
+        );
+
+        assert!(submission(json!({})).gallery_urls().is_empty());
+    }
+
+    #[test]
+    fn test_preview() {
+        let image = submission(json!({
+            "domain": "i.redd.it", "is_self": false, "url": "https://i.redd.it/abc.jpg",
+            "thumbnail": "https://b.thumbs.redditmedia.com/abc.jpg",
+            "preview": {"enabled": true, "images": [{
+                "id": "abc",
+                "source": {"url": "https://preview.redd.it/abc.jpg?auto=webp&s=1", "width": 1024,
+                    "height": 768},
+                "resolutions": [
+                    {"url": "https://preview.redd.it/abc.jpg?width=108&s=2", "width": 108,
+                        "height": 81},
+                    {"url": "https://preview.redd.it/abc.jpg?width=216&s=3", "width": 216,
+                        "height": 162}
+                ],
+                "variants": {}
+            }]}
+        }));
+
+        assert_eq!(
+            image.thumbnail,
+            Thumbnail::Url("https://b.thumbs.redditmedia.com/abc.jpg".to_owned())
+        );
+
+        let preview = &image.preview.unwrap().images[0];
+        assert_eq!(preview.source.width, 1024);
+        assert_eq!(
+            preview.source.url,
+            "https://preview.redd.it/abc.jpg?auto=webp&s=1"
+        );
+        assert_eq!(preview.resolutions.len(), 2);
+
+        assert_eq!(submission(json!({})).thumbnail, Thumbnail::SelfPost);
+        assert_eq!(
+            submission(json!({"thumbnail": "nsfw"})).thumbnail,
+            Thumbnail::Nsfw
+        );
+        assert_eq!(
+            submission(json!({"thumbnail": "spoiler"})).thumbnail,
+            Thumbnail::Spoiler
+        );
+        assert_eq!(
+            submission(json!({"thumbnail": "default"})).thumbnail,
+            Thumbnail::Default
+        );
+    }
+
+    #[test]
+    fn test_poll_data() {
+        let poll = submission(json!({"poll_data": {
+            "prediction_status": null, "total_stake_amount": null,
+            "voting_end_timestamp": 1600259200000u64, "options": [
+                {"text": "Yes", "id": "1", "vote_count": 12},
+                {"text": "No", "id": "2", "vote_count": 3}
+            ], "vote_updates_remained": null, "is_prediction": false,
+            "resolved_option_id": null, "user_won_amount": null, "user_selection": "1",
+            "tournament_id": null, "total_vote_count": 15
+        }}));
+
+        let poll = poll.poll_data.unwrap();
+        assert_eq!(poll.total_vote_count, 15);
+        assert_eq!(poll.voting_end_timestamp, 1600259200000);
+        assert_eq!(poll.user_selection.as_deref(), Some("1"));
+        assert_eq!(poll.options[0].text, "Yes");
+        assert_eq!(poll.options[1].vote_count, Some(3));
+
+        assert!(submission(json!({})).poll_data.is_none());
+    }
+
+    #[test]
+    fn test_deleted() {
+        let deleted = submission(json!({
+            "author": "[deleted]", "selftext": "[deleted]", "author_flair_text": null,
+            "author_flair_css_class": null, "link_flair_text": null, "thumbnail": ""
+        }));
+        assert!(deleted.is_deleted());
+
+        let deleted = submission(json!({"author": null}));
+        assert!(deleted.is_deleted());
+        assert_eq!(deleted.author, "[deleted]");
+
+        assert!(!submission(json!({})).is_deleted());
+    }
+
+    #[test]
+    fn test_full_permalink() {
+        assert_eq!(
+            submission(json!({})).full_permalink(),
+            "https://www.reddit.com/r/rust/comments/abc/title/"
+        );
+    }
+
+    #[test]
+    fn test_flair() {
+        let flaired = submission(json!({
+            "link_flair_text": "Help", "link_flair_css_class": "help", "hide_score": true
+        }));
+        assert_eq!(flaired.link_flair_text.as_deref(), Some("Help"));
+        assert_eq!(flaired.link_flair_css_class.as_deref(), Some("help"));
+        assert!(flaired.hide_score);
+
+        let unflaired = submission(json!({}));
+        assert_eq!(unflaired.link_flair_text, None);
+        assert_eq!(unflaired.link_flair_css_class, None);
+    }
+
+    #[test]
+    fn test_crosspost() {
+        let original = submission_json(json!({}));
+        let crosspost = submission(json!({
+            "id": "def", "name": "t3_def", "subreddit": "learnrust", "domain": "self.rust",
+            "crosspost_parent": "t3_abc", "crosspost_parent_list": [original]
+        }));
+
+        assert_eq!(crosspost.crosspost_parent.as_deref(), Some("t3_abc"));
+
+        let parents = crosspost.crosspost_parent_list.unwrap();
+        assert_eq!(parents[0].name, "t3_abc");
+        assert_eq!(parents[0].subreddit, "rust");
+
+        assert!(submission(json!({})).crosspost_parent_list.is_none());
+    }
+
+    #[test]
+    fn test_awardings() {
+        let awarded = submission(json!({
+            "gilded": 1, "total_awards_received": 3, "all_awardings": [
+                {"id": "gid_2", "name": "Gold", "description": "Gives 100 Reddit Coins.",
+                    "icon_url": "https://www.redditstatic.com/gold/awards/icon/gold_512.png",
+                    "count": 1, "coin_price": 500, "award_type": "global"},
+                {"id": "award_5f123e3d", "name": "Wholesome", "description": null,
+                    "icon_url": "https://i.redd.it/award_images/wholesome.png", "count": 2,
+                    "coin_price": 125}
+            ]
+        }));
+
+        assert_eq!(awarded.total_awards_received, 3);
+        assert_eq!(awarded.all_awardings[0].name, "Gold");
+        assert_eq!(awarded.all_awardings[1].count, 2);
+
+        let old = submission(json!({}));
+        assert_eq!(old.total_awards_received, 0);
+        assert!(old.all_awardings.is_empty());
+    }
+}
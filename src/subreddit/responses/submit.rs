@@ -0,0 +1,65 @@
+//! # Subreddit Submit Responses
+use crate::responses::ApiResponse;
+use serde::Deserialize;
+
+/// SubmittedPost
+#[derive(Debug, Deserialize)]
+pub struct SubmittedPost {
+    /// The ID of the new post.
+    pub id: String,
+    /// The full 'Thing ID' of the new post, including the leading `t3_`.
+    pub name: String,
+    /// The URL of the new post's comments page.
+    pub url: String,
+}
+
+/// Submitted
+pub type Submitted = ApiResponse<SubmittedPost>;
+
+#[cfg(test)]
+mod tests {
+    use super::Submitted;
+    use crate::util::{ApiErrorKind, RouxError};
+
+    #[test]
+    fn test_submitted() {
+        let submitted = r#"{"json": {"errors": [], "data": {"url":
+            "https://www.reddit.com/r/test/comments/abc/title/", "drafts_count": 0,
+            "id": "abc", "name": "t3_abc"}}}"#;
+        let submitted: Submitted = serde_json::from_str(submitted).unwrap();
+        let post = submitted.into_result().unwrap();
+
+        assert_eq!(post.id, "abc");
+        assert_eq!(post.name, "t3_abc");
+
+        let rate_limited = r#"{"json": {"errors": [["RATELIMIT",
+            "you are doing that too much. try again in 5 minutes.", "ratelimit"]]}}"#;
+        let rate_limited: Submitted = serde_json::from_str(rate_limited).unwrap();
+
+        match rate_limited.into_result() {
+            Err(RouxError::Api(err)) => {
+                assert_eq!(err.kind, ApiErrorKind::RateLimit);
+                assert_eq!(err.field.as_deref(), Some("ratelimit"));
+            }
+            _ => panic!("expected an api error"),
+        }
+
+        let captcha = r#"{"json": {"errors": [["BAD_CAPTCHA", "care to try these again?",
+            "captcha"]], "captcha": "abc"}}"#;
+        let captcha: Submitted = serde_json::from_str(captcha).unwrap();
+
+        match captcha.into_result() {
+            Err(RouxError::Api(err)) => assert_eq!(err.kind, ApiErrorKind::BadCaptcha),
+            _ => panic!("expected an api error"),
+        }
+
+        let already_submitted = r#"{"json": {"errors": [["ALREADY_SUB",
+            "that link has already been submitted", "url"]]}}"#;
+        let already_submitted: Submitted = serde_json::from_str(already_submitted).unwrap();
+
+        match already_submitted.into_result() {
+            Err(RouxError::Api(err)) => assert_eq!(err.kind, ApiErrorKind::AlreadySubmitted),
+            _ => panic!("expected an api error"),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! # Subreddit Traffic Responses
+use serde::{Deserialize, Deserializer};
+
+/// Traffic of a subreddit in one hour, day or month.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrafficPoint {
+    /// A timestamp of the start of the period, in **UTC**.
+    pub timestamp: u64,
+    /// The number of unique visitors.
+    pub uniques: u64,
+    /// The number of page views.
+    pub pageviews: u64,
+    /// The number of new subscribers. Only counted per day.
+    pub subscriptions: Option<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawTrafficPoint {
+    WithSubscriptions(u64, u64, u64, u64),
+    Visits(u64, u64, u64),
+}
+
+impl<'de> Deserialize<'de> for TrafficPoint {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (timestamp, uniques, pageviews, subscriptions) =
+            match RawTrafficPoint::deserialize(deserializer)? {
+                RawTrafficPoint::WithSubscriptions(
+                    timestamp,
+                    uniques,
+                    pageviews,
+                    subscriptions,
+                ) => (timestamp, uniques, pageviews, Some(subscriptions)),
+                RawTrafficPoint::Visits(timestamp, uniques, pageviews) => {
+                    (timestamp, uniques, pageviews, None)
+                }
+            };
+
+        Ok(TrafficPoint {
+            timestamp,
+            uniques,
+            pageviews,
+            subscriptions,
+        })
+    }
+}
+
+/// Traffic
+#[derive(Debug, Deserialize)]
+pub struct Traffic {
+    /// Traffic per day, newest first.
+    pub day: Vec<TrafficPoint>,
+    /// Traffic per hour, newest first.
+    pub hour: Vec<TrafficPoint>,
+    /// Traffic per month, newest first.
+    pub month: Vec<TrafficPoint>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Traffic, TrafficPoint};
+
+    #[test]
+    fn test_traffic() {
+        let traffic = r#"{
+            "day": [[1600041600, 1200, 5400, 30], [1599955200, 1100, 5000, 25]],
+            "hour": [[1600124400, 80, 300]],
+            "month": [[1598918400, 25000, 160000]]
+        }"#;
+        let traffic: Traffic = serde_json::from_str(traffic).unwrap();
+
+        assert_eq!(
+            traffic.day[0],
+            TrafficPoint {
+                timestamp: 1600041600,
+                uniques: 1200,
+                pageviews: 5400,
+                subscriptions: Some(30),
+            }
+        );
+        assert_eq!(traffic.hour[0].pageviews, 300);
+        assert_eq!(traffic.month[0].subscriptions, None);
+    }
+}
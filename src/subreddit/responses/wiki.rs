@@ -0,0 +1,34 @@
+//! # Subreddit Wiki Responses
+use crate::responses::BasicThing;
+use serde::Deserialize;
+
+/// WikiPage
+#[derive(Debug, Deserialize)]
+pub struct WikiPage {
+    /// The content of the page in **Markdown** format.
+    pub content_md: String,
+    /// The content of the page in HTML format.
+    pub content_html: String,
+    /// A timestamp of the time when the page was last revised, in **UTC**.
+    pub revision_date: Option<f64>,
+    /// The user who last revised the page, if known.
+    pub revision_by: Option<BasicThing<WikiRevisionAuthor>>,
+    /// The ID of the latest revision.
+    pub revision_id: Option<String>,
+    /// This is `true` if the logged-in user can edit the page.
+    pub may_revise: bool,
+}
+
+/// WikiRevisionAuthor
+#[derive(Debug, Deserialize)]
+pub struct WikiRevisionAuthor {
+    /// The name of the user (not including the leading `/u/`).
+    pub name: String,
+}
+
+/// Wiki
+pub type Wiki = BasicThing<WikiPage>;
+
+/// WikiPages
+/// The names of the pages of a wiki.
+pub type WikiPages = BasicThing<Vec<String>>;
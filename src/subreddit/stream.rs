@@ -0,0 +1,306 @@
+//! # Subreddit Streams
+//! Streams of content in a subreddit: paginated feeds, and poll-based streams of new content.
+
+use std::cmp;
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use futures::stream::{self, Stream};
+use tokio::time::delay_for;
+
+use super::responses::{Submissions, SubmissionsData, SubredditCommentsData};
+use super::Subreddit;
+use crate::util::{FeedOption, RouxError};
+
+/// Longest delay between polls after repeated errors.
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+/// Number of fullnames remembered to deduplicate polls.
+const SEEN_CAPACITY: usize = 1000;
+
+/// Fullnames that have already been yielded, forgetting the oldest past `SEEN_CAPACITY`.
+struct Seen {
+    names: HashSet<String>,
+    order: VecDeque<String>,
+}
+
+impl Seen {
+    fn new() -> Seen {
+        Seen {
+            names: HashSet::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Returns `true` if `name` had not been seen before.
+    fn insert(&mut self, name: &str) -> bool {
+        if !self.names.insert(name.to_owned()) {
+            return false;
+        }
+
+        self.order.push_back(name.to_owned());
+
+        if self.order.len() > SEEN_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.names.remove(&oldest);
+            }
+        }
+
+        true
+    }
+}
+
+struct StreamState<T> {
+    seen: Seen,
+    pending: VecDeque<T>,
+    delay: Option<Duration>,
+}
+
+struct PageState {
+    options: Option<FeedOption>,
+    pending: VecDeque<SubmissionsData>,
+}
+
+impl Subreddit {
+    /// Walk the pages of a feed, `limit` posts at a time, until the end of the listing.
+    /// Ends after yielding the first error.
+    ///
+    /// If `options` has a `before` cursor the feed is walked backwards, towards the start
+    /// of the listing, and each page is reversed so posts come in the opposite order of
+    /// the feed (e.g. oldest first for `new`).
+    ///
+    /// `count` is increased by the size of each page, so Reddit numbers the next one
+    /// where the last left off.
+    fn paged_feed(
+        &self,
+        ty: &'static str,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> impl Stream<Item = Result<SubmissionsData, RouxError>> + '_ {
+        let state = PageState {
+            options: Some(options.unwrap_or_default()),
+            pending: VecDeque::new(),
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(submission) = state.pending.pop_front() {
+                    return Some((Ok(submission), state));
+                }
+
+                let options = state.options.take()?;
+                let reverse = options.before.is_some();
+                let mut next = options.clone();
+
+                match self.get_feed::<Submissions>(ty, limit, Some(options)).await {
+                    Ok(submissions) => {
+                        let count =
+                            next.count.unwrap_or(0) + submissions.data.children.len() as u32;
+
+                        let listing = submissions.data;
+                        next.count = Some(count);
+
+                        state.options = if reverse {
+                            listing.before.map(|before| {
+                                next.before = Some(before);
+                                next
+                            })
+                        } else {
+                            listing.after.map(|after| {
+                                next.after = Some(after);
+                                next
+                            })
+                        };
+
+                        let children = listing.children.into_iter().map(|c| c.data);
+
+                        if reverse {
+                            state.pending.extend(children.rev());
+                        } else {
+                            state.pending.extend(children);
+                        }
+                    }
+                    Err(e) => return Some((Err(e), state)),
+                }
+            }
+        })
+    }
+
+    /// Stream hot posts across pages.
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use roux::Subreddit;
+    ///
+    /// # async fn example() {
+    /// let subreddit = Subreddit::new("rust");
+    ///
+    /// // Up to 200 posts, 25 per request.
+    /// let posts: Vec<_> = subreddit.hot_paged(25, None).take(200).collect().await;
+    /// # }
+    /// ```
+    pub fn hot_paged(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> impl Stream<Item = Result<SubmissionsData, RouxError>> + '_ {
+        self.paged_feed("hot", limit, options)
+    }
+
+    /// Stream rising posts across pages.
+    pub fn rising_paged(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> impl Stream<Item = Result<SubmissionsData, RouxError>> + '_ {
+        self.paged_feed("rising", limit, options)
+    }
+
+    /// Stream top posts across pages.
+    pub fn top_paged(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> impl Stream<Item = Result<SubmissionsData, RouxError>> + '_ {
+        self.paged_feed("top", limit, options)
+    }
+
+    /// Stream latest posts across pages.
+    pub fn latest_paged(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> impl Stream<Item = Result<SubmissionsData, RouxError>> + '_ {
+        self.paged_feed("new", limit, options)
+    }
+
+    /// Stream posts newer than the post with fullname `before`, oldest first, to catch up on
+    /// everything posted since it was seen.
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use roux::Subreddit;
+    ///
+    /// # async fn example() {
+    /// let subreddit = Subreddit::new("rust");
+    /// let last_seen = "t3_jrjqy0";
+    ///
+    /// let new_posts: Vec<_> = subreddit.latest_since(last_seen, 25).collect().await;
+    /// # }
+    /// ```
+    pub fn latest_since(
+        &self,
+        before: &str,
+        limit: u32,
+    ) -> impl Stream<Item = Result<SubmissionsData, RouxError>> + '_ {
+        self.paged_feed("new", limit, Some(FeedOption::new().before(before)))
+    }
+
+    /// Stream controversial posts across pages.
+    pub fn controversial_paged(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> impl Stream<Item = Result<SubmissionsData, RouxError>> + '_ {
+        self.paged_feed("controversial", limit, options)
+    }
+
+    /// Stream new submissions, polling the `new` feed every `poll_interval`.
+    ///
+    /// Each submission is only yielded once, oldest first. Request errors are yielded
+    /// without ending the stream and double the delay before the next poll.
+    pub fn stream_submissions(
+        &self,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<SubmissionsData, RouxError>> + '_ {
+        let state = StreamState {
+            seen: Seen::new(),
+            pending: VecDeque::new(),
+            delay: None,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(submission) = state.pending.pop_front() {
+                    return Some((Ok(submission), state));
+                }
+
+                if let Some(delay) = state.delay {
+                    delay_for(delay).await;
+                }
+
+                match self.latest(100, None).await {
+                    Ok(submissions) => {
+                        state.delay = Some(poll_interval);
+
+                        for child in submissions.data.children.into_iter().rev() {
+                            if state.seen.insert(&child.data.name) {
+                                state.pending.push_back(child.data);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        state.delay = Some(backoff(state.delay, poll_interval));
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+    }
+
+    /// Stream new comments anywhere in the subreddit, polling `latest_comments` every
+    /// `poll_interval`.
+    ///
+    /// Each comment is only yielded once, oldest first. Request errors are yielded
+    /// without ending the stream and double the delay before the next poll.
+    pub fn stream_comments(
+        &self,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<SubredditCommentsData, RouxError>> + '_ {
+        let state = StreamState {
+            seen: Seen::new(),
+            pending: VecDeque::new(),
+            delay: None,
+        };
+
+        stream::unfold(state, move |mut state| async move {
+            loop {
+                if let Some(comment) = state.pending.pop_front() {
+                    return Some((Ok(comment), state));
+                }
+
+                if let Some(delay) = state.delay {
+                    delay_for(delay).await;
+                }
+
+                match self.latest_comments(None, Some(100)).await {
+                    Ok(comments) => {
+                        state.delay = Some(poll_interval);
+
+                        for child in comments.data.children.into_iter().rev() {
+                            // Skip `more` stubs, which have no name.
+                            let is_new = match &child.data.name {
+                                Some(name) => state.seen.insert(name),
+                                None => false,
+                            };
+
+                            if is_new {
+                                state.pending.push_back(child.data);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        state.delay = Some(backoff(state.delay, poll_interval));
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Delay before the next poll after an error, doubling the last one up to `MAX_BACKOFF`
+/// (or `poll_interval` if that's longer).
+fn backoff(delay: Option<Duration>, poll_interval: Duration) -> Duration {
+    let backoff = delay.unwrap_or(poll_interval) * 2;
+    cmp::min(backoff, cmp::max(poll_interval, MAX_BACKOFF))
+}
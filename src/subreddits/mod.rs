@@ -0,0 +1,207 @@
+//! # Subreddits
+//! A read-only module to discover subreddits, rather than read a single one.
+//!
+//! # Usage
+//! ```rust
+//! use roux::Subreddits;
+//! use tokio;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let subreddits = Subreddits::new();
+//!     // Now you are able to:
+//!
+//!     // Get the most popular subreddits.
+//!     let popular = subreddits.popular(25, None).await;
+//!
+//!     // Get recently created subreddits.
+//!     let latest = subreddits.latest(25, None).await;
+//!
+//!     // Suggest subreddits as the user types.
+//!     let candidates = subreddits.autocomplete("rus", false, false).await;
+//! }
+//! ```
+
+extern crate reqwest;
+extern crate serde_json;
+
+pub mod responses;
+
+use crate::subreddit::responses::SubredditListing;
+use crate::util::request::Sender;
+use crate::util::{FeedOption, RateLimit, RouxError};
+use reqwest::Client;
+use responses::Autocomplete;
+
+/// Subreddits.
+pub struct Subreddits {
+    sender: Sender,
+}
+
+impl Default for Subreddits {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Subreddits {
+    /// Create a new `Subreddits` instance.
+    pub fn new() -> Subreddits {
+        Self::new_with_http_client(Client::new())
+    }
+
+    /// Create a new `Subreddits` instance with a provided HTTP client.
+    pub fn new_with_http_client(http_client: Client) -> Subreddits {
+        Subreddits {
+            sender: Sender::new(http_client),
+        }
+    }
+
+    /// Retry rate limited requests, see `Subreddit::with_max_retries`.
+    pub fn with_max_retries(mut self, max_retries: u32) -> Subreddits {
+        self.sender.max_retries = max_retries;
+        self
+    }
+
+    /// Rate limit state reported with the last response, see `Subreddit::last_rate_limit`.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.sender.last_rate_limit()
+    }
+
+    async fn get_listing(
+        &self,
+        ty: &str,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<SubredditListing, RouxError> {
+        let url = format!("https://www.reddit.com/subreddits/{}.json", ty);
+        let mut params = vec![("limit", limit.to_string())];
+
+        if let Some(options) = options {
+            params.extend(options.params());
+        }
+
+        self.sender.get(&url, &params).await
+    }
+
+    /// Get the most popular subreddits.
+    pub async fn popular(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<SubredditListing, RouxError> {
+        self.get_listing("popular", limit, options).await
+    }
+
+    /// Get the most recently created subreddits.
+    ///
+    /// Named `latest` like `Subreddit::latest`, as `new` creates the instance.
+    pub async fn latest(
+        &self,
+        limit: u32,
+        options: Option<FeedOption>,
+    ) -> Result<SubredditListing, RouxError> {
+        self.get_listing("new", limit, options).await
+    }
+
+    /// Get subreddits whose names start with `query`, for search-as-you-type.
+    ///
+    /// NSFW subreddits are only included with `include_over_18`, and users matching the
+    /// query only with `include_profiles`.
+    pub async fn autocomplete(
+        &self,
+        query: &str,
+        include_over_18: bool,
+        include_profiles: bool,
+    ) -> Result<Autocomplete, RouxError> {
+        let params = [
+            ("query", query.to_owned()),
+            ("include_over_18", include_over_18.to_string()),
+            ("include_profiles", include_profiles.to_string()),
+        ];
+
+        self.sender
+            .get(
+                "https://www.reddit.com/api/subreddit_autocomplete_v2.json",
+                &params,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Subreddits;
+    use crate::util::transport::tests::MockTransport;
+    use crate::util::FeedOption;
+    use std::sync::Arc;
+    use tokio;
+
+    #[tokio::test]
+    async fn test_listing_request() {
+        let transport = Arc::new(MockTransport::json(
+            200,
+            r#"{"kind": "Listing", "data": {"children": []}}"#,
+        ));
+        let mut subreddits = Subreddits::new();
+        subreddits.sender.transport = transport.clone();
+
+        let options = FeedOption::new().after("t5_2qh1i");
+        assert!(subreddits
+            .latest(5, Some(options))
+            .await
+            .unwrap()
+            .is_empty());
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].url().path(), "/subreddits/new.json");
+        assert!(requests[0]
+            .url()
+            .query()
+            .unwrap()
+            .contains("after=t5_2qh1i"));
+        assert!(requests[0].url().query().unwrap().contains("raw_json=1"));
+    }
+
+    #[tokio::test]
+    async fn test_no_auth() {
+        let subreddits = Subreddits::new();
+
+        // Test popular
+        let popular = subreddits.popular(5, None).await;
+        assert!(popular.is_ok());
+
+        let popular = popular.unwrap();
+        assert_eq!(popular.data.children.len(), 5);
+        assert!(popular
+            .data
+            .children
+            .iter()
+            .all(|subreddit| !subreddit.data.display_name.is_empty()));
+
+        // Test paging
+        let after = popular.data.after.unwrap();
+        let next = subreddits
+            .popular(5, Some(FeedOption::new().after(&after)))
+            .await
+            .unwrap();
+        assert_ne!(
+            next.data.children[0].data.display_name,
+            popular.data.children[0].data.display_name
+        );
+
+        // Test latest
+        let latest = subreddits.latest(5, None).await;
+        assert!(latest.is_ok());
+
+        // Test autocomplete
+        let candidates = subreddits.autocomplete("rus", false, false).await;
+        assert!(candidates.is_ok());
+        assert!(candidates
+            .unwrap()
+            .data
+            .children
+            .iter()
+            .any(|candidate| candidate.name() == "rust"));
+    }
+}
@@ -0,0 +1,88 @@
+//! # Autocomplete Responses
+use crate::responses::{BasicThing, Listing};
+use serde::Deserialize;
+
+/// SubredditCandidate
+#[derive(Debug, Deserialize)]
+pub struct SubredditCandidate {
+    /// The subreddit name, not including the leading `/r/`.
+    pub display_name: String,
+    /// The subreddit name, including the leading `r/`.
+    pub display_name_prefixed: String,
+    /// The number of subscribers.
+    #[serde(default)]
+    pub subscribers: u64,
+    /// This is `true` if the subreddit is marked NSFW.
+    pub over18: Option<bool>,
+}
+
+/// ProfileCandidate
+#[derive(Debug, Deserialize)]
+pub struct ProfileCandidate {
+    /// The username, not including the leading `/u/`.
+    pub name: String,
+}
+
+/// A subreddit or, with `include_profiles`, a user matching the query.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "kind", content = "data")]
+pub enum AutocompleteCandidate {
+    /// A subreddit.
+    #[serde(rename = "t5")]
+    Subreddit(SubredditCandidate),
+    /// A user.
+    #[serde(rename = "t2")]
+    Profile(ProfileCandidate),
+}
+
+impl AutocompleteCandidate {
+    /// The subreddit name or username.
+    pub fn name(&self) -> &str {
+        match self {
+            AutocompleteCandidate::Subreddit(subreddit) => &subreddit.display_name,
+            AutocompleteCandidate::Profile(profile) => &profile.name,
+        }
+    }
+}
+
+/// Autocomplete
+pub type Autocomplete = BasicThing<Listing<AutocompleteCandidate>>;
+
+#[cfg(test)]
+mod tests {
+    use super::{Autocomplete, AutocompleteCandidate};
+
+    #[test]
+    fn test_autocomplete() {
+        let json = r#"{
+            "kind": "Listing",
+            "data": {
+                "after": null,
+                "before": null,
+                "children": [
+                    {
+                        "kind": "t5",
+                        "data": {
+                            "display_name": "rust",
+                            "display_name_prefixed": "r/rust",
+                            "subscribers": 250000,
+                            "over18": false
+                        }
+                    },
+                    {"kind": "t2", "data": {"name": "rustacean"}}
+                ]
+            }
+        }"#;
+
+        let autocomplete: Autocomplete = serde_json::from_str(json).unwrap();
+        let candidates = autocomplete.data.children;
+
+        assert_eq!(candidates[0].name(), "rust");
+        assert!(matches!(
+            &candidates[0],
+            AutocompleteCandidate::Subreddit(subreddit) if subreddit.subscribers == 250000
+        ));
+        assert_eq!(candidates[1].name(), "rustacean");
+        assert!(matches!(candidates[1], AutocompleteCandidate::Profile(_)));
+    }
+}
@@ -0,0 +1,4 @@
+//! # Subreddits Responses
+mod autocomplete;
+
+pub use autocomplete::{Autocomplete, AutocompleteCandidate, ProfileCandidate, SubredditCandidate};
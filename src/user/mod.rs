@@ -19,23 +19,36 @@
 //!
 //!     // Get comments.
 //!     let comments = user.comments().await;
+//!
+//!     // Get karma, account age, etc.
+//!     let about = user.about().await;
+//!
+//!     // Get trophies.
+//!     let trophies = user.trophies().await;
 //! }
 //! ```
 
 extern crate reqwest;
 extern crate serde_json;
 
-use crate::util::RouxError;
+use std::sync::Arc;
+
+use crate::auth::Auth;
+use crate::responses::Items;
+use crate::util::request::Sender;
+use crate::util::{RateLimit, RouxError};
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 
 pub mod responses;
-use responses::{UserComments, Overview, Submitted};
+use responses::{Overview, Submitted, Trophies, Trophy, UserAbout, UserAboutData, UserComments};
 
 /// User.
 pub struct User {
     /// User's name.
     pub user: String,
-    client: Client,
+    sender: Sender,
+    auth: Option<Arc<Auth>>,
 }
 
 impl User {
@@ -48,58 +61,139 @@ impl User {
     pub fn new_with_http_client(user: &str, http_client: Client) -> User {
         User {
             user: user.to_owned(),
-            client: http_client,
+            sender: Sender::new(http_client),
+            auth: None,
+        }
+    }
+
+    /// Create a new `User` instance with an OAuth client, see `Me::user`.
+    pub(crate) fn new_oauth(user: &str, http_client: Client, auth: Arc<Auth>) -> User {
+        User {
+            auth: Some(auth),
+            ..Self::new_with_http_client(user, http_client)
         }
     }
 
+    /// Retry rate limited requests, see `Subreddit::with_max_retries`.
+    pub fn with_max_retries(mut self, max_retries: u32) -> User {
+        self.sender.max_retries = max_retries;
+        self
+    }
+
+    /// Rate limit state reported with the last response, see `Subreddit::last_rate_limit`.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.sender.last_rate_limit()
+    }
+
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, RouxError> {
+        let host = match &self.auth {
+            Some(_) => "https://oauth.reddit.com",
+            None => "https://www.reddit.com",
+        };
+        let url = format!("{}/user/{}/{}", host, self.user, path);
+
+        self.sender
+            .send(self.sender.client.get(&url), self.auth.as_deref())
+            .await
+    }
+
     /// Get user's overview.
     pub async fn overview(&self) -> Result<Overview, RouxError> {
-        Ok(self
-            .client
-            .get(&format!(
-                "https://www.reddit.com/user/{}/overview/.json",
-                self.user
-            ))
-            .send()
-            .await?
-            .json::<Overview>()
-            .await?)
+        self.get("overview/.json").await
     }
 
     /// Get user's submitted posts.
     pub async fn submitted(&self) -> Result<Submitted, RouxError> {
-        Ok(self
-            .client
-            .get(&format!(
-                "https://www.reddit.com/user/{}/submitted/.json",
-                self.user
-            ))
-            .send()
-            .await?
-            .json::<Submitted>()
-            .await?)
+        self.get("submitted/.json").await
     }
 
     /// Get user's submitted comments.
     pub async fn comments(&self) -> Result<UserComments, RouxError> {
-        Ok(self
-            .client
-            .get(&format!(
-                "https://www.reddit.com/user/{}/comments/.json",
-                self.user
-            ))
-            .send()
-            .await?
-            .json::<UserComments>()
-            .await?)
+        self.get("comments/.json").await
+    }
+
+    /// Get user's karma, account age, etc.
+    pub async fn about(&self) -> Result<UserAboutData, RouxError> {
+        let about = self.get::<UserAbout>("about/.json").await?;
+
+        Ok(about.data)
+    }
+
+    /// Get the user's trophies, e.g. for account age. Empty if the user has none.
+    pub async fn trophies(&self) -> Result<Vec<Trophy>, RouxError> {
+        let trophies = self.get::<Trophies>("trophies/.json").await?;
+
+        Ok(trophies
+            .data
+            .trophies
+            .into_iter()
+            .map(|trophy| trophy.data)
+            .collect())
+    }
+
+    /// Get the posts and comments the user saved.
+    /// Only the logged in user can see these, with a `User` from `Me::user`.
+    pub async fn saved(&self) -> Result<Items, RouxError> {
+        self.get_private("saved/.json").await
+    }
+
+    /// Get the posts and comments the user upvoted.
+    /// Only the logged in user can see these, with a `User` from `Me::user`.
+    pub async fn upvoted(&self) -> Result<Items, RouxError> {
+        self.get_private("upvoted/.json").await
+    }
+
+    /// Get the posts and comments the user downvoted.
+    /// Only the logged in user can see these, with a `User` from `Me::user`.
+    pub async fn downvoted(&self) -> Result<Items, RouxError> {
+        self.get_private("downvoted/.json").await
+    }
+
+    async fn get_private<T: DeserializeOwned>(&self, path: &str) -> Result<T, RouxError> {
+        if self.auth.is_none() {
+            return Err(RouxError::Unauthenticated);
+        }
+
+        self.get(path).await
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::User;
+    use crate::util::transport::tests::{response, MockTransport};
+    use crate::util::RouxError;
+    use std::sync::Arc;
+    use std::time::Duration;
     use tokio;
 
+    #[tokio::test]
+    async fn test_retries() {
+        let mut limited = response(429, "");
+        limited
+            .headers_mut()
+            .insert("retry-after", "0".parse().unwrap());
+        let mut empty = response(200, r#"{"kind": "Listing", "data": {"children": []}}"#);
+        for (name, value) in &[
+            ("x-ratelimit-remaining", "599.0"),
+            ("x-ratelimit-used", "1"),
+            ("x-ratelimit-reset", "60"),
+        ] {
+            empty.headers_mut().insert(*name, value.parse().unwrap());
+        }
+
+        let transport = Arc::new(MockTransport::new(vec![limited, empty]));
+        let mut user = User::new("beneater").with_max_retries(1);
+        user.sender.transport = transport.clone();
+
+        assert!(user.submitted().await.unwrap().is_empty());
+        assert_eq!(transport.requests().len(), 2);
+
+        let rate_limit = user.last_rate_limit().unwrap();
+        assert_eq!(rate_limit.used, 1);
+        assert_eq!(rate_limit.reset, Duration::from_secs(60));
+    }
+
     #[tokio::test]
     async fn test_no_auth() {
         let user = User::new("beneater");
@@ -115,5 +209,17 @@ mod tests {
         // Test comments
         let comments = user.comments().await;
         assert!(comments.is_ok());
+
+        // Test about
+        let about = user.about().await;
+        assert!(about.is_ok());
+
+        // Test trophies
+        let trophies = user.trophies().await;
+        assert!(trophies.is_ok());
+
+        // Test saved
+        let saved = user.saved().await;
+        assert!(matches!(saved, Err(RouxError::Unauthenticated)));
     }
 }
@@ -0,0 +1,41 @@
+//! # User About Responses
+use crate::responses::BasicThing;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// UserAboutData
+#[derive(Debug, Deserialize)]
+pub struct UserAboutData {
+    /// ID
+    pub id: String,
+    /// Name
+    pub name: String,
+    /// Link karma
+    pub link_karma: i32,
+    /// Comment karma
+    pub comment_karma: i32,
+    /// Created
+    pub created: f64,
+    /// Created (UTC)
+    pub created_utc: f64,
+    /// Is gold
+    pub is_gold: bool,
+    /// Is mod
+    pub is_mod: bool,
+    /// Has verified email
+    pub has_verified_email: Option<bool>,
+    /// Icon img
+    pub icon_img: String,
+}
+
+impl UserAboutData {
+    /// `created_utc` as a `DateTime<Utc>`.
+    #[cfg(feature = "chrono")]
+    pub fn created_utc_datetime(&self) -> Option<DateTime<Utc>> {
+        crate::util::time::from_timestamp(self.created_utc)
+    }
+}
+
+/// UserAbout
+pub type UserAbout = BasicThing<UserAboutData>;
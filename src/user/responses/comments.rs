@@ -1,5 +1,7 @@
 //! # User Comment Responses
 use crate::responses::BasicListing;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 /// UserCommentsData
@@ -21,5 +23,13 @@ pub struct UserCommentsData {
     pub created_utc: u64,
 }
 
+impl UserCommentsData {
+    /// `created_utc` as a `DateTime<Utc>`.
+    #[cfg(feature = "chrono")]
+    pub fn created_utc_datetime(&self) -> Option<DateTime<Utc>> {
+        crate::util::time::from_timestamp(self.created_utc as f64)
+    }
+}
+
 /// UserComments
 pub type UserComments = BasicListing<UserCommentsData>;
@@ -7,3 +7,9 @@ pub use submitted::{Submitted, SubmittedData};
 
 pub mod comments;
 pub use comments::{UserComments, UserCommentsData};
+
+pub mod about;
+pub use about::{UserAbout, UserAboutData};
+
+pub mod trophies;
+pub use trophies::{Trophies, TrophiesData, Trophy};
@@ -18,6 +18,10 @@ pub struct OverviewData {
     /// Body
     #[serde(default = "default_string")]
     pub body: String,
+    /// Body rendered as HTML, if this is a comment
+    pub body_html: Option<String>,
+    /// Self text rendered as HTML, if this is a self post
+    pub selftext_html: Option<String>,
     /// Link title
     #[serde(default = "default_string")]
     pub link_title: String,
@@ -0,0 +1,52 @@
+//! # User Trophies Responses
+use crate::responses::BasicThing;
+use serde::Deserialize;
+
+/// Trophy
+#[derive(Debug, Deserialize)]
+pub struct Trophy {
+    /// The name of the trophy, e.g. `Five-Year Club`.
+    pub name: String,
+    /// What the trophy was given for, if it says.
+    pub description: Option<String>,
+    /// The URL of the 70x70 icon of the trophy.
+    pub icon_70: String,
+    /// The ID of the award the trophy is for, if any.
+    pub award_id: Option<String>,
+    /// A timestamp of the time when the trophy was granted, in **UTC**, if known.
+    pub granted_at: Option<f64>,
+}
+
+/// TrophiesData
+#[derive(Debug, Deserialize)]
+pub struct TrophiesData {
+    /// The trophies of the user. Empty if they have none.
+    #[serde(default)]
+    pub trophies: Vec<BasicThing<Trophy>>,
+}
+
+/// Trophies
+pub type Trophies = BasicThing<TrophiesData>;
+
+#[cfg(test)]
+mod tests {
+    use super::Trophies;
+
+    #[test]
+    fn test_trophies() {
+        let trophies = r#"{"kind": "TrophyList", "data": {"trophies": [{"kind": "t6", "data": {
+            "icon_70": "https://www.redditstatic.com/awards2/3_year_club-70.png",
+            "granted_at": null, "url": null,
+            "icon_40": "https://www.redditstatic.com/awards2/3_year_club-40.png",
+            "name": "Three-Year Club", "award_id": null, "id": null, "description": null}}]}}"#;
+        let trophies: Trophies = serde_json::from_str(trophies).unwrap();
+
+        let trophy = &trophies.data.trophies[0].data;
+        assert_eq!(trophy.name, "Three-Year Club");
+        assert_eq!(trophy.award_id, None);
+
+        let empty: Trophies =
+            serde_json::from_str(r#"{"kind": "TrophyList", "data": {"trophies": []}}"#).unwrap();
+        assert!(empty.data.trophies.is_empty());
+    }
+}
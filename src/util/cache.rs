@@ -0,0 +1,80 @@
+//! # Response cache
+//! Bodies of responses with an `ETag`, to send `If-None-Match` with the next request for
+//! the same URL and reuse the body if Reddit answers `304 Not Modified`.
+
+use std::collections::{HashMap, VecDeque};
+
+/// Number of URLs a `ResponseCache` remembers by default.
+pub(crate) const DEFAULT_CAPACITY: usize = 100;
+
+struct CachedResponse {
+    etag: String,
+    body: String,
+}
+
+/// Response bodies by URL, forgetting the oldest past `capacity`.
+pub(crate) struct ResponseCache {
+    capacity: usize,
+    responses: HashMap<String, CachedResponse>,
+    order: VecDeque<String>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(capacity: usize) -> ResponseCache {
+        ResponseCache {
+            capacity,
+            responses: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// The `ETag` of the cached response for `url`.
+    pub(crate) fn etag(&self, url: &str) -> Option<&str> {
+        self.responses.get(url).map(|response| &response.etag[..])
+    }
+
+    /// The body of the cached response for `url`.
+    pub(crate) fn body(&self, url: &str) -> Option<&str> {
+        self.responses.get(url).map(|response| &response.body[..])
+    }
+
+    pub(crate) fn insert(&mut self, url: &str, etag: String, body: String) {
+        let response = CachedResponse { etag, body };
+
+        if self.responses.insert(url.to_owned(), response).is_some() {
+            return;
+        }
+
+        self.order.push_back(url.to_owned());
+
+        if self.order.len() > self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.responses.remove(&oldest);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResponseCache;
+
+    #[test]
+    fn test_cache() {
+        let mut cache = ResponseCache::new(2);
+        assert_eq!(cache.etag("a"), None);
+
+        cache.insert("a", "\"1\"".to_owned(), "first".to_owned());
+        assert_eq!(cache.etag("a"), Some("\"1\""));
+        assert_eq!(cache.body("a"), Some("first"));
+
+        cache.insert("a", "\"2\"".to_owned(), "second".to_owned());
+        assert_eq!(cache.body("a"), Some("second"));
+
+        cache.insert("b", "\"3\"".to_owned(), "b".to_owned());
+        cache.insert("c", "\"4\"".to_owned(), "c".to_owned());
+        assert_eq!(cache.body("a"), None);
+        assert_eq!(cache.body("b"), Some("b"));
+        assert_eq!(cache.body("c"), Some("c"));
+    }
+}
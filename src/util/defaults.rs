@@ -1,4 +1,24 @@
+use serde::{Deserialize, Deserializer};
+
+/// Author of deleted posts and comments.
+pub const DELETED_AUTHOR: &str = "[deleted]";
+
 /// String function for serde defaults.
 pub fn default_string() -> String {
     "".to_string()
 }
+
+/// Deserializes an author, which Reddit sometimes sends as `null` for deleted content,
+/// as `DELETED_AUTHOR`.
+pub fn deserialize_author<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let author = Option::<String>::deserialize(deserializer)?;
+    Ok(author.unwrap_or_else(|| DELETED_AUTHOR.to_owned()))
+}
+
+/// Like `deserialize_author`, for an author that's missing from some responses. Use with
+/// `#[serde(default)]` so a missing author stays `None` while `null` becomes `DELETED_AUTHOR`.
+pub fn deserialize_optional_author<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    deserialize_author(deserializer).map(Some)
+}
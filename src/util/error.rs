@@ -1,11 +1,16 @@
 use std::error;
 use std::fmt;
+use std::time::Duration;
 
 use reqwest;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use serde_json;
 
 /// Error type that occurs when an API request fails for some reason.
 #[derive(Debug)]
+// `Status` carries the whole response, which is larger with the compression features.
+#[allow(clippy::large_enum_variant)]
 pub enum RouxError {
     /// Occurs when the API has returned a non-success error code.
     Status(reqwest::Response),
@@ -14,6 +19,122 @@ pub enum RouxError {
     Network(reqwest::Error),
     /// Occurs if serde could not Deserialize the response.
     Parse(serde_json::Error),
+    /// Occurs when Reddit responds with a JSON error body instead of the requested data,
+    /// e.g. `{"error": 404, "message": "Not Found", "reason": "banned"}`.
+    RedditError {
+        /// Error code, usually mirroring an HTTP status.
+        error: u16,
+        /// Error message.
+        message: String,
+        /// Reason for the error (`banned`, `private`, `quarantined`...), if given.
+        reason: Option<String>,
+    },
+    /// Occurs when a method is called with an argument Reddit would reject,
+    /// before any request is made.
+    InvalidArgument(String),
+    /// Occurs when Reddit rejects a request made with `api_type=json`, listing the
+    /// reason in the response's `errors`.
+    Api(ApiError),
+    /// Occurs when a method that needs a logged in user is called on a client that isn't,
+    /// e.g. a `Subreddit` created without `Me::subreddit`.
+    Unauthenticated,
+    /// Occurs when Reddit responds with a `403` status to an action the logged in user
+    /// isn't allowed to take, e.g. a moderator action outside of their subreddits.
+    Forbidden,
+}
+
+/// Kind of an `ApiError`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiErrorKind {
+    /// Too many requests of this kind were made recently (`RATELIMIT`).
+    RateLimit,
+    /// A captcha must be solved to do this (`BAD_CAPTCHA`).
+    BadCaptcha,
+    /// The link has already been submitted to the subreddit (`ALREADY_SUB`).
+    AlreadySubmitted,
+    /// The recipient of a message blocked the user, or only accepts messages from
+    /// certain users (`USER_BLOCKED_MESSAGE`, `NOT_WHITELISTED_BY_USER_MESSAGE`).
+    UserBlocked,
+    /// There is no user with that name (`USER_DOESNT_EXIST`).
+    UserNotFound,
+    /// The content belongs to another user (`NOT_AUTHOR`).
+    NotAuthor,
+    /// The subreddit doesn't allow crossposts (`INVALID_CROSSPOST_DST`).
+    CrosspostNotAllowed,
+    /// Any other error, with Reddit's code for it.
+    Other(String),
+}
+
+impl From<&str> for ApiErrorKind {
+    fn from(code: &str) -> Self {
+        match code {
+            "RATELIMIT" => ApiErrorKind::RateLimit,
+            "BAD_CAPTCHA" => ApiErrorKind::BadCaptcha,
+            "ALREADY_SUB" => ApiErrorKind::AlreadySubmitted,
+            "USER_BLOCKED_MESSAGE" | "NOT_WHITELISTED_BY_USER_MESSAGE" => ApiErrorKind::UserBlocked,
+            "USER_DOESNT_EXIST" => ApiErrorKind::UserNotFound,
+            "NOT_AUTHOR" => ApiErrorKind::NotAuthor,
+            "INVALID_CROSSPOST_DST" => ApiErrorKind::CrosspostNotAllowed,
+            code => ApiErrorKind::Other(code.to_owned()),
+        }
+    }
+}
+
+/// An error in the `errors` of an `api_type=json` response,
+/// e.g. `["RATELIMIT", "you are doing that too much. try again in 5 minutes.", "ratelimit"]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// Kind of error.
+    pub kind: ApiErrorKind,
+    /// Human readable explanation.
+    pub message: String,
+    /// Form field the error is about, if any.
+    pub field: Option<String>,
+}
+
+impl ApiError {
+    /// How long to wait before trying again, for `ApiErrorKind::RateLimit` errors.
+    ///
+    /// Reddit only gives this in the message, e.g. "try again in 5 minutes.".
+    pub fn retry_after(&self) -> Option<Duration> {
+        if self.kind != ApiErrorKind::RateLimit {
+            return None;
+        }
+
+        let words: Vec<_> = self.message.split_whitespace().collect();
+
+        words.windows(2).find_map(|pair| {
+            let amount: u64 = pair[0].parse().ok()?;
+
+            match pair[1].trim_end_matches('.') {
+                "second" | "seconds" => Some(Duration::from_secs(amount)),
+                "minute" | "minutes" => Some(Duration::from_secs(amount * 60)),
+                "hour" | "hours" => Some(Duration::from_secs(amount * 60 * 60)),
+                _ => None,
+            }
+        })
+    }
+}
+
+/// JSON error body Reddit sometimes returns with a `200` status.
+#[derive(Deserialize)]
+struct ErrorBody {
+    error: u16,
+    message: String,
+    reason: Option<String>,
+}
+
+/// Deserializes a response body, turning Reddit's JSON error bodies into `RouxError::RedditError`.
+pub(crate) fn from_body<T: DeserializeOwned>(body: &str) -> Result<T, RouxError> {
+    if let Ok(err) = serde_json::from_str::<ErrorBody>(body) {
+        return Err(RouxError::RedditError {
+            error: err.error,
+            message: err.message,
+            reason: err.reason,
+        });
+    }
+
+    Ok(serde_json::from_str::<T>(body)?)
 }
 
 impl From<reqwest::Error> for RouxError {
@@ -32,8 +153,20 @@ impl fmt::Display for RouxError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             RouxError::Status(ref err) => write!(f, "Status error: {}", err.status()),
-            RouxError::Network(ref err) => err.fmt(f),
-            RouxError::Parse(ref err) => err.fmt(f),
+            RouxError::Network(_) => write!(f, "Network error"),
+            RouxError::Parse(_) => write!(f, "Failed to parse response"),
+            RouxError::RedditError {
+                error,
+                ref message,
+                ref reason,
+            } => match reason {
+                Some(reason) => write!(f, "Reddit error {}: {} ({})", error, message, reason),
+                None => write!(f, "Reddit error {}: {}", error, message),
+            },
+            RouxError::InvalidArgument(ref message) => write!(f, "Invalid argument: {}", message),
+            RouxError::Api(ref err) => write!(f, "API error: {}", err.message),
+            RouxError::Unauthenticated => write!(f, "Not logged in"),
+            RouxError::Forbidden => write!(f, "Not allowed to do that"),
         }
     }
 }
@@ -43,7 +176,118 @@ impl error::Error for RouxError {
         match *self {
             RouxError::Status(_) => None,
             RouxError::Network(ref err) => Some(err),
-            RouxError::Parse(ref err) => Some(err)
+            RouxError::Parse(ref err) => Some(err),
+            RouxError::RedditError { .. } => None,
+            RouxError::InvalidArgument(_) => None,
+            RouxError::Api(_) => None,
+            RouxError::Unauthenticated => None,
+            RouxError::Forbidden => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{from_body, ApiError, ApiErrorKind, RouxError};
+    use crate::subreddit::responses::Submissions;
+    use std::error::Error;
+    use std::time::Duration;
+    use tokio;
+
+    #[test]
+    fn test_reddit_error_body() {
+        let body = r#"{"reason": "banned", "message": "Not Found", "error": 404}"#;
+
+        match from_body::<Submissions>(body) {
+            Err(RouxError::RedditError {
+                error,
+                message,
+                reason,
+            }) => {
+                assert_eq!(error, 404);
+                assert_eq!(message, "Not Found");
+                assert_eq!(reason.as_deref(), Some("banned"));
+            }
+            _ => panic!("expected a reddit error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_source() {
+        let err = reqwest::Client::new()
+            .get("not a url")
+            .send()
+            .await
+            .unwrap_err();
+        let err = RouxError::from(err);
+
+        assert!(matches!(err, RouxError::Network(_)));
+        assert!(err.source().is_some());
+        // The detail is left to `source()`, so error chains don't print it twice.
+        assert_eq!(err.to_string(), "Network error");
+        assert_ne!(err.to_string(), err.source().unwrap().to_string());
+
+        let err = from_body::<Submissions>("{").unwrap_err();
+
+        assert!(matches!(err, RouxError::Parse(_)));
+        assert!(err.source().is_some());
+        assert_eq!(err.to_string(), "Failed to parse response");
+        assert_ne!(err.to_string(), err.source().unwrap().to_string());
+
+        assert!(RouxError::Unauthenticated.source().is_none());
+        assert!(RouxError::Forbidden.source().is_none());
+    }
+
+    #[test]
+    fn test_api_error_kind() {
+        assert_eq!(ApiErrorKind::from("RATELIMIT"), ApiErrorKind::RateLimit);
+        assert_eq!(
+            ApiErrorKind::from("ALREADY_SUB"),
+            ApiErrorKind::AlreadySubmitted
+        );
+        assert_eq!(
+            ApiErrorKind::from("NOT_WHITELISTED_BY_USER_MESSAGE"),
+            ApiErrorKind::UserBlocked
+        );
+        assert_eq!(
+            ApiErrorKind::from("USER_DOESNT_EXIST"),
+            ApiErrorKind::UserNotFound
+        );
+        assert_eq!(ApiErrorKind::from("NOT_AUTHOR"), ApiErrorKind::NotAuthor);
+        assert_eq!(
+            ApiErrorKind::from("INVALID_CROSSPOST_DST"),
+            ApiErrorKind::CrosspostNotAllowed
+        );
+        assert_eq!(
+            ApiErrorKind::from("NO_TEXT"),
+            ApiErrorKind::Other("NO_TEXT".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let error = |kind, message: &str| ApiError {
+            kind,
+            message: message.to_owned(),
+            field: Some("ratelimit".to_owned()),
+        };
+
+        let rate_limit = error(
+            ApiErrorKind::RateLimit,
+            "you are doing that too much. try again in 5 minutes.",
+        );
+        assert_eq!(rate_limit.retry_after(), Some(Duration::from_secs(300)));
+
+        let rate_limit = error(
+            ApiErrorKind::RateLimit,
+            "Looks like you've been doing that a lot. Take a break for 9 seconds before trying again.",
+        );
+        assert_eq!(rate_limit.retry_after(), Some(Duration::from_secs(9)));
+
+        let rate_limit = error(ApiErrorKind::RateLimit, "try again in 1 second.");
+        assert_eq!(rate_limit.retry_after(), Some(Duration::from_secs(1)));
+
+        let other = error(ApiErrorKind::BadCaptcha, "try again in 5 minutes.");
+        assert_eq!(other.retry_after(), None);
+    }
+}
@@ -1,10 +1,26 @@
+/// Response caching.
+pub(crate) mod cache;
 /// Defaults for serde.
 pub mod defaults;
 /// Error responses.
 pub mod error;
 /// Url building.
 pub mod url;
-pub use error::RouxError;
+pub use error::{ApiError, ApiErrorKind, RouxError};
 /// Options
 pub mod option;
-pub use option::FeedOption;
+/// Rate limits
+pub mod ratelimit;
+/// Sending requests and reading responses.
+pub(crate) mod request;
+/// Timestamps
+#[cfg(feature = "chrono")]
+pub mod time;
+/// Sending requests
+pub mod transport;
+pub use option::{
+    BanOptions, CommentSort, DistinguishKind, FeedOption, SearchOptions, SearchSort, SearchType,
+    TimeFilter, VoteDirection,
+};
+pub use ratelimit::RateLimit;
+pub use transport::Transport;
@@ -4,15 +4,65 @@
 //! Listing JSON responses contain after and before fields which are equivalent to the
 //! "next" and "prev" buttons on the site and in combination with count can be used to page
 //! through the listing.
+//!
+//! When paging manually, pass the `after` of the last page along with the total number of
+//! items seen so far as `count`. The `*_paged` streams on `Subreddit` do this for you.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::util::RouxError;
+
+/// Time period to filter `top` and `controversial` feeds by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeFilter {
+    /// Past hour.
+    Hour,
+    /// Past day.
+    Day,
+    /// Past week.
+    Week,
+    /// Past month.
+    Month,
+    /// Past year.
+    Year,
+    /// All time.
+    All,
+}
+
+impl fmt::Display for TimeFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            TimeFilter::Hour => "hour",
+            TimeFilter::Day => "day",
+            TimeFilter::Week => "week",
+            TimeFilter::Month => "month",
+            TimeFilter::Year => "year",
+            TimeFilter::All => "all",
+        };
+
+        write!(f, "{}", s)
+    }
+}
 
 /// Basic feed options
+#[derive(Debug, Clone)]
 pub struct FeedOption {
     /// `after` and `before` indicate the fullname of an item in the listing to use as the anchor point of the slice.
     pub after: Option<String>,
     /// Only one should be specified.
     pub before: Option<String>,
-    /// The number of items already seen in this listing.
+    /// The number of items already seen in this listing, i.e. the sum of the sizes of all
+    /// previous pages. Reddit uses it to number the items of the next page.
     pub count: Option<u32>,
+    /// Time period for `top` and `controversial` feeds. Ignored by other feeds.
+    pub timefilter: Option<TimeFilter>,
+}
+
+impl Default for FeedOption {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl FeedOption {
@@ -22,12 +72,13 @@ impl FeedOption {
             after: None,
             before: None,
             count: None,
+            timefilter: None,
         }
     }
 
     /// Set after param.
     pub fn after(mut self, ty: &str) -> FeedOption {
-        if !self.before.is_none() {
+        if self.before.is_some() {
             panic!("Cannot have an after and before param at the same time");
         }
 
@@ -37,7 +88,7 @@ impl FeedOption {
 
     /// Set before param.
     pub fn before(mut self, ty: &str) -> FeedOption {
-        if !self.after.is_none() {
+        if self.after.is_some() {
             panic!("Cannot have an after and before param at the same time");
         }
 
@@ -50,4 +101,433 @@ impl FeedOption {
         self.count = Some(ty);
         self
     }
+
+    /// Set time filter param.
+    pub fn timefilter(mut self, ty: TimeFilter) -> FeedOption {
+        self.timefilter = Some(ty);
+        self
+    }
+
+    /// Query parameters for these options.
+    pub(crate) fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        if let Some(after) = &self.after {
+            params.push(("after", after.to_owned()));
+        } else if let Some(before) = &self.before {
+            params.push(("before", before.to_owned()));
+        }
+
+        if let Some(count) = self.count {
+            params.push(("count", count.to_string()));
+        }
+
+        if let Some(timefilter) = self.timefilter {
+            params.push(("t", timefilter.to_string()));
+        }
+
+        params
+    }
+}
+
+/// Order of the comments of a submission.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CommentSort {
+    /// Best first (`confidence`), Reddit's default.
+    Confidence,
+    /// Highest scoring first.
+    Top,
+    /// Newest first.
+    New,
+    /// Most controversial first.
+    Controversial,
+    /// Oldest first.
+    Old,
+    /// Comments answered by the submitter first, for AMAs.
+    Qa,
+}
+
+impl fmt::Display for CommentSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            CommentSort::Confidence => "confidence",
+            CommentSort::Top => "top",
+            CommentSort::New => "new",
+            CommentSort::Controversial => "controversial",
+            CommentSort::Old => "old",
+            CommentSort::Qa => "qa",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// Parses a sort as Reddit names it, e.g. a submission's `suggested_sort`.
+impl FromStr for CommentSort {
+    type Err = RouxError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "confidence" => Ok(CommentSort::Confidence),
+            "top" => Ok(CommentSort::Top),
+            "new" => Ok(CommentSort::New),
+            "controversial" => Ok(CommentSort::Controversial),
+            "old" => Ok(CommentSort::Old),
+            "qa" => Ok(CommentSort::Qa),
+            _ => Err(RouxError::InvalidArgument(format!(
+                "unknown comment sort {}",
+                s
+            ))),
+        }
+    }
+}
+
+/// Direction of a vote.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VoteDirection {
+    /// Upvote.
+    Up = 1,
+    /// Downvote.
+    Down = -1,
+    /// Remove a previous vote.
+    Clear = 0,
+}
+
+impl fmt::Display for VoteDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", *self as i8)
+    }
+}
+
+/// How a moderator's post or comment is distinguished.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DistinguishKind {
+    /// As a moderator.
+    Yes,
+    /// Not at all, undoing a previous distinguish.
+    No,
+    /// As a Reddit admin. Only admins can use this.
+    Admin,
+    /// As a special distinction, only available to some users.
+    Special,
+}
+
+impl fmt::Display for DistinguishKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            DistinguishKind::Yes => "yes",
+            DistinguishKind::No => "no",
+            DistinguishKind::Admin => "admin",
+            DistinguishKind::Special => "special",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// Sort order for search results.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchSort {
+    /// Most relevant first.
+    Relevance,
+    /// Hottest first.
+    Hot,
+    /// Highest scoring first.
+    Top,
+    /// Newest first.
+    New,
+    /// Most commented first.
+    Comments,
+}
+
+impl fmt::Display for SearchSort {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            SearchSort::Relevance => "relevance",
+            SearchSort::Hot => "hot",
+            SearchSort::Top => "top",
+            SearchSort::New => "new",
+            SearchSort::Comments => "comments",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// Kind of results to search for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SearchType {
+    /// Posts.
+    Link,
+    /// Subreddits.
+    Subreddit,
+    /// Users.
+    User,
+}
+
+impl fmt::Display for SearchType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            SearchType::Link => "link",
+            SearchType::Subreddit => "sr",
+            SearchType::User => "user",
+        };
+
+        write!(f, "{}", s)
+    }
+}
+
+/// Search options
+pub struct SearchOptions {
+    /// Sort order of the results.
+    pub sort: Option<SearchSort>,
+    /// Time period to search in.
+    pub timefilter: Option<TimeFilter>,
+    /// Maximum number of results.
+    pub limit: Option<u32>,
+    /// `after` and `before` indicate the fullname of an item in the listing to use as the anchor point of the slice.
+    pub after: Option<String>,
+    /// Only one should be specified.
+    pub before: Option<String>,
+    /// The number of items already seen in this listing.
+    pub count: Option<u32>,
+    /// Whether a search in a subreddit only returns results from it. Defaults to `true`
+    /// for `Subreddit::search`, and has no effect elsewhere.
+    pub restrict_sr: Option<bool>,
+    /// Kind of results to search for. Posts by default.
+    pub search_type: Option<SearchType>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchOptions {
+    /// Create a new `SearchOptions` instance.
+    pub fn new() -> SearchOptions {
+        SearchOptions {
+            sort: None,
+            timefilter: None,
+            limit: None,
+            after: None,
+            before: None,
+            count: None,
+            restrict_sr: None,
+            search_type: None,
+        }
+    }
+
+    /// Set sort param.
+    pub fn sort(mut self, ty: SearchSort) -> SearchOptions {
+        self.sort = Some(ty);
+        self
+    }
+
+    /// Set time filter param.
+    pub fn timefilter(mut self, ty: TimeFilter) -> SearchOptions {
+        self.timefilter = Some(ty);
+        self
+    }
+
+    /// Set limit param.
+    pub fn limit(mut self, ty: u32) -> SearchOptions {
+        self.limit = Some(ty);
+        self
+    }
+
+    /// Set after param.
+    pub fn after(mut self, ty: &str) -> SearchOptions {
+        if self.before.is_some() {
+            panic!("Cannot have an after and before param at the same time");
+        }
+
+        self.after = Some(ty.to_owned());
+        self
+    }
+
+    /// Set before param.
+    pub fn before(mut self, ty: &str) -> SearchOptions {
+        if self.after.is_some() {
+            panic!("Cannot have an after and before param at the same time");
+        }
+
+        self.before = Some(ty.to_owned());
+        self
+    }
+
+    /// Set count param.
+    pub fn count(mut self, ty: u32) -> SearchOptions {
+        self.count = Some(ty);
+        self
+    }
+
+    /// Set restrict_sr param.
+    pub fn restrict_sr(mut self, restrict: bool) -> SearchOptions {
+        self.restrict_sr = Some(restrict);
+        self
+    }
+
+    /// Set type param. Only posts parse as `Submissions`; search for other kinds with
+    /// `Subreddit::search_raw`.
+    pub fn search_type(mut self, ty: SearchType) -> SearchOptions {
+        self.search_type = Some(ty);
+        self
+    }
+
+    /// Query parameters for these options.
+    pub(crate) fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        if let Some(sort) = self.sort {
+            params.push(("sort", sort.to_string()));
+        }
+
+        if let Some(timefilter) = self.timefilter {
+            params.push(("t", timefilter.to_string()));
+        }
+
+        if let Some(limit) = self.limit {
+            params.push(("limit", limit.to_string()));
+        }
+
+        if let Some(after) = &self.after {
+            params.push(("after", after.to_owned()));
+        } else if let Some(before) = &self.before {
+            params.push(("before", before.to_owned()));
+        }
+
+        if let Some(count) = self.count {
+            params.push(("count", count.to_string()));
+        }
+
+        if let Some(restrict_sr) = self.restrict_sr {
+            params.push(("restrict_sr", restrict_sr.to_string()));
+        }
+
+        if let Some(search_type) = self.search_type {
+            params.push(("type", search_type.to_string()));
+        }
+
+        params
+    }
+}
+
+/// Options for banning a user from a subreddit.
+pub struct BanOptions {
+    /// Length of the ban in days, or permanent if `None`.
+    pub duration: Option<u32>,
+    /// Reason for the ban, only visible to moderators.
+    pub ban_reason: Option<String>,
+    /// Message sent to the banned user.
+    pub ban_message: Option<String>,
+    /// Note about the ban, only visible to moderators.
+    pub note: Option<String>,
+}
+
+impl Default for BanOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BanOptions {
+    /// Create a new `BanOptions` instance, for a permanent ban.
+    pub fn new() -> BanOptions {
+        BanOptions {
+            duration: None,
+            ban_reason: None,
+            ban_message: None,
+            note: None,
+        }
+    }
+
+    /// Set the length of the ban in days.
+    pub fn duration(mut self, days: u32) -> BanOptions {
+        self.duration = Some(days);
+        self
+    }
+
+    /// Set the reason for the ban.
+    pub fn ban_reason(mut self, reason: &str) -> BanOptions {
+        self.ban_reason = Some(reason.to_owned());
+        self
+    }
+
+    /// Set the message sent to the banned user.
+    pub fn ban_message(mut self, message: &str) -> BanOptions {
+        self.ban_message = Some(message.to_owned());
+        self
+    }
+
+    /// Set the note about the ban.
+    pub fn note(mut self, note: &str) -> BanOptions {
+        self.note = Some(note.to_owned());
+        self
+    }
+
+    /// Form parameters for these options.
+    pub(crate) fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+
+        if let Some(duration) = self.duration {
+            params.push(("duration", duration.to_string()));
+        }
+
+        if let Some(ban_reason) = &self.ban_reason {
+            params.push(("ban_reason", ban_reason.to_owned()));
+        }
+
+        if let Some(ban_message) = &self.ban_message {
+            params.push(("ban_message", ban_message.to_owned()));
+        }
+
+        if let Some(note) = &self.note {
+            params.push(("note", note.to_owned()));
+        }
+
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BanOptions, CommentSort, SearchOptions, SearchType};
+
+    #[test]
+    fn test_comment_sort_from_str() {
+        assert_eq!("qa".parse::<CommentSort>().unwrap(), CommentSort::Qa);
+        assert_eq!(
+            "controversial".parse::<CommentSort>().unwrap(),
+            CommentSort::Controversial
+        );
+        assert!("best".parse::<CommentSort>().is_err());
+    }
+
+    #[test]
+    fn test_search_options_params() {
+        let params = SearchOptions::new()
+            .restrict_sr(false)
+            .search_type(SearchType::Subreddit)
+            .params();
+        assert_eq!(
+            params,
+            vec![
+                ("restrict_sr", "false".to_owned()),
+                ("type", "sr".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ban_options_params() {
+        assert!(BanOptions::new().params().is_empty());
+
+        let params = BanOptions::new().duration(7).note("spam").params();
+        assert_eq!(
+            params,
+            vec![("duration", "7".to_owned()), ("note", "spam".to_owned())]
+        );
+    }
 }
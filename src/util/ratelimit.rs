@@ -0,0 +1,139 @@
+//! # Rate limits
+//! Reddit allows a limited number of requests per period, and responds with
+//! `429 Too Many Requests` once they're used up.
+
+use std::cmp;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+/// Rate limit state reported by Reddit with each response.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    /// Requests left in the current period.
+    pub remaining: f64,
+    /// Requests made in the current period.
+    pub used: u32,
+    /// Time until the current period ends.
+    pub reset: Duration,
+}
+
+impl RateLimit {
+    /// Parses the `X-Ratelimit-*` headers of a response, if all of them are present.
+    pub fn from_headers(headers: &HeaderMap) -> Option<RateLimit> {
+        Some(RateLimit {
+            remaining: header_secs(headers, "x-ratelimit-remaining")?,
+            used: header_secs(headers, "x-ratelimit-used")? as u32,
+            reset: header_duration(headers, "x-ratelimit-reset", MAX_RESET)?,
+        })
+    }
+}
+
+/// Longest delay before retrying a rate limited request, when Reddit doesn't say.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Longest `reset` taken from `X-Ratelimit-Reset`, the length of Reddit's rate limit period.
+const MAX_RESET: Duration = Duration::from_secs(600);
+
+/// Longest delay before retrying a rate limited request, when Reddit does say. Waiting
+/// longer than a rate limit period never helps.
+const MAX_RETRY_AFTER: Duration = MAX_RESET;
+
+/// Parses a header holding a (possibly fractional) number.
+fn header_secs(headers: &HeaderMap, name: &str) -> Option<f64> {
+    headers.get(name)?.to_str().ok()?.trim().parse::<f64>().ok()
+}
+
+/// Parses a header holding a number of seconds, between zero and `max`. Values that aren't
+/// finite (`inf`, `NaN`) are ignored, as `Duration` can't hold them.
+fn header_duration(headers: &HeaderMap, name: &str, max: Duration) -> Option<Duration> {
+    let secs = header_secs(headers, name)?;
+
+    if !secs.is_finite() {
+        return None;
+    }
+
+    Some(Duration::from_secs_f64(
+        secs.max(0.0).min(max.as_secs_f64()),
+    ))
+}
+
+/// How long to wait before retrying a rate limited request for the `retries + 1`th time.
+///
+/// Uses `Retry-After` or `X-Ratelimit-Reset` when present (up to `MAX_RETRY_AFTER`), doubling
+/// from one second otherwise.
+pub(crate) fn retry_delay(headers: &HeaderMap, retries: u32) -> Duration {
+    let secs = header_secs(headers, RETRY_AFTER.as_str())
+        .or_else(|| header_secs(headers, "x-ratelimit-reset"));
+
+    match secs {
+        Some(secs) if secs.is_finite() && secs >= 0.0 => {
+            Duration::from_secs_f64(secs.min(MAX_RETRY_AFTER.as_secs_f64()))
+        }
+        _ => cmp::min(Duration::from_secs(1 << cmp::min(retries, 6)), MAX_BACKOFF),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{retry_delay, RateLimit};
+    use reqwest::header::{HeaderMap, HeaderValue};
+    use std::time::Duration;
+
+    #[test]
+    fn test_retry_delay() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_delay(&headers, 0), Duration::from_secs(1));
+        assert_eq!(retry_delay(&headers, 2), Duration::from_secs(4));
+        assert_eq!(retry_delay(&headers, 10), Duration::from_secs(60));
+
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("12"));
+        assert_eq!(retry_delay(&headers, 0), Duration::from_secs(12));
+
+        headers.insert("retry-after", HeaderValue::from_static("3"));
+        assert_eq!(retry_delay(&headers, 0), Duration::from_secs(3));
+
+        headers.insert("retry-after", HeaderValue::from_static("1e30"));
+        assert_eq!(retry_delay(&headers, 0), Duration::from_secs(600));
+
+        headers.insert("retry-after", HeaderValue::from_static("86400"));
+        assert_eq!(retry_delay(&headers, 0), Duration::from_secs(600));
+
+        // Not a usable delay, so the backoff applies.
+        headers.insert("retry-after", HeaderValue::from_static("inf"));
+        assert_eq!(retry_delay(&headers, 0), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("598.0"));
+        headers.insert("x-ratelimit-used", HeaderValue::from_static("2"));
+        assert_eq!(RateLimit::from_headers(&headers), None);
+
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("421"));
+        assert_eq!(
+            RateLimit::from_headers(&headers),
+            Some(RateLimit {
+                remaining: 598.0,
+                used: 2,
+                reset: Duration::from_secs(421),
+            })
+        );
+
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("inf"));
+        assert_eq!(RateLimit::from_headers(&headers), None);
+
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("1e30"));
+        assert_eq!(
+            RateLimit::from_headers(&headers).unwrap().reset,
+            Duration::from_secs(600)
+        );
+
+        headers.insert("x-ratelimit-reset", HeaderValue::from_static("-5"));
+        assert_eq!(
+            RateLimit::from_headers(&headers).unwrap().reset,
+            Duration::from_secs(0)
+        );
+    }
+}
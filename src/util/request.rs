@@ -0,0 +1,158 @@
+//! # Requests
+//! Sending requests to Reddit and reading their responses, shared by every module so they
+//! all retry, track rate limits and cache the same way.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::{header, Client, Method, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
+use tokio::time::delay_for;
+
+use crate::auth::Auth;
+use crate::util::cache::ResponseCache;
+use crate::util::ratelimit::retry_delay;
+use crate::util::{error::from_body, RateLimit, RouxError, Transport};
+
+/// Sends requests and deserializes their responses.
+///
+/// Clones share the rate limit state and response cache.
+#[derive(Clone)]
+pub(crate) struct Sender {
+    /// Builds requests.
+    pub(crate) client: Client,
+    /// Sends built requests, `client` unless replaced.
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) max_retries: u32,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) allow_nsfw: bool,
+    pub(crate) cache: Option<Arc<Mutex<ResponseCache>>>,
+    rate_limit: Arc<Mutex<Option<RateLimit>>>,
+}
+
+impl Sender {
+    pub(crate) fn new(client: Client) -> Sender {
+        Sender {
+            transport: Arc::new(client.clone()),
+            client,
+            max_retries: 0,
+            timeout: None,
+            allow_nsfw: false,
+            cache: None,
+            rate_limit: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Rate limit state reported with the last response.
+    pub(crate) fn last_rate_limit(&self) -> Option<RateLimit> {
+        *self.rate_limit.lock().unwrap()
+    }
+
+    /// Get `url` with the query `params`, without logging in.
+    pub(crate) async fn get<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        params: &[(&str, String)],
+    ) -> Result<T, RouxError> {
+        self.send(self.client.get(url).query(params), None).await
+    }
+
+    /// Send `request`, as the logged in user if there is `auth`.
+    pub(crate) async fn send<T: DeserializeOwned>(
+        &self,
+        mut request: RequestBuilder,
+        auth: Option<&Auth>,
+    ) -> Result<T, RouxError> {
+        // Only `GET` requests are cached, by their full URL.
+        let cache_key = self.cache.as_ref().and_then(|_| {
+            let built = request.try_clone()?.build().ok()?;
+
+            if built.method() == Method::GET {
+                Some(built.url().to_string())
+            } else {
+                None
+            }
+        });
+
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some(etag) = cache.lock().unwrap().etag(key) {
+                request = request.header(header::IF_NONE_MATCH, etag);
+            }
+        }
+
+        let response = self.send_raw(request, auth).await?;
+
+        if response.status() == StatusCode::NOT_MODIFIED {
+            if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+                let body = cache.lock().unwrap().body(key).map(str::to_owned);
+
+                if let Some(body) = body {
+                    return from_body(&body);
+                }
+            }
+        }
+
+        // Private, banned and quarantined subreddits (as well as rate limiting) all
+        // return an error status with a body that won't deserialize into `T`.
+        if !response.status().is_success() {
+            return Err(RouxError::Status(response));
+        }
+
+        let etag = response
+            .headers()
+            .get(header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(str::to_owned);
+        let body = response.text().await?;
+
+        if let (Some(cache), Some(key), Some(etag)) = (&self.cache, cache_key, etag) {
+            cache.lock().unwrap().insert(&key, etag, body.clone());
+        }
+
+        from_body(&body)
+    }
+
+    /// Send `request` like `send`, but without caching and returning the response whatever
+    /// its status.
+    pub(crate) async fn send_raw(
+        &self,
+        request: RequestBuilder,
+        auth: Option<&Auth>,
+    ) -> Result<Response, RouxError> {
+        // Without `raw_json` Reddit escapes `&`, `<` and `>` in all text as HTML entities.
+        let mut request = request.query(&[("raw_json", "1")]);
+
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+
+        if self.allow_nsfw {
+            request = request.header(header::COOKIE, "over18=1");
+        }
+
+        let mut retries = 0;
+
+        loop {
+            // Only streamed bodies can't be cloned, and none are sent.
+            let attempt = request.try_clone().unwrap();
+
+            let attempt = match auth {
+                Some(auth) => attempt.bearer_auth(auth.ensure_token().await?),
+                None => attempt,
+            };
+
+            let response = self.transport.execute(attempt.build()?).await?;
+
+            if let Some(rate_limit) = RateLimit::from_headers(response.headers()) {
+                *self.rate_limit.lock().unwrap() = Some(rate_limit);
+            }
+
+            if response.status() != StatusCode::TOO_MANY_REQUESTS || retries >= self.max_retries {
+                return Ok(response);
+            }
+
+            delay_for(retry_delay(response.headers(), retries)).await;
+            retries += 1;
+        }
+    }
+}
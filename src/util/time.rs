@@ -0,0 +1,33 @@
+//! # Time
+//! Conversion of Reddit's timestamps into `chrono` types, enabled by the `chrono` feature.
+//!
+//! Reddit sends timestamps as float seconds since the Unix epoch. Responses keep those
+//! raw values (e.g. `created_utc: f64`) and have methods returning them as `DateTime<Utc>`.
+use chrono::{DateTime, TimeZone, Utc};
+
+/// Converts float seconds since the Unix epoch into a `DateTime<Utc>`.
+/// Returns `None` if the timestamp is out of range.
+pub fn from_timestamp(timestamp: f64) -> Option<DateTime<Utc>> {
+    if !timestamp.is_finite() {
+        return None;
+    }
+
+    let secs = timestamp.floor();
+    let nanos = ((timestamp - secs) * 1e9) as u32;
+
+    Utc.timestamp_opt(secs as i64, nanos).single()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::from_timestamp;
+
+    #[test]
+    fn test_from_timestamp() {
+        let date = from_timestamp(1_600_000_000.5).unwrap();
+        assert_eq!(date.timestamp(), 1_600_000_000);
+        assert_eq!(date.timestamp_subsec_millis(), 500);
+
+        assert!(from_timestamp(f64::NAN).is_none());
+    }
+}
@@ -0,0 +1,147 @@
+//! # Transport
+//! How a `Subreddit` sends its requests, so they can be answered without reaching Reddit.
+//!
+//! `reqwest::Client` is the default transport. In tests a mock can answer with canned
+//! responses instead, built from an `http::Response`:
+//!
+//! ```
+//! use roux::util::transport::{ResponseFuture, Transport};
+//! use roux::Subreddit;
+//! use reqwest::{Request, Response};
+//!
+//! struct EmptyListings;
+//!
+//! impl Transport for EmptyListings {
+//!     fn execute(&self, _request: Request) -> ResponseFuture<'_> {
+//!         let body = r#"{"kind": "Listing", "data": {"children": []}}"#;
+//!         let response = Response::from(http::Response::new(body));
+//!
+//!         Box::pin(async move { Ok(response) })
+//!     }
+//! }
+//!
+//! # #[tokio::main]
+//! # async fn main() {
+//! let subreddit = Subreddit::new("rust").with_transport(EmptyListings);
+//!
+//! assert!(subreddit.hot(25, None).await.unwrap().is_empty());
+//! # }
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use reqwest::{Client, Request, Response};
+
+/// The response to a request sent by a `Transport`.
+pub type ResponseFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Response, reqwest::Error>> + Send + 'a>>;
+
+/// Sends requests and returns their responses, like `reqwest::Client::execute`.
+///
+/// Requests are already complete when they reach the transport, with the URL, query,
+/// headers (including authorization) and body set. Rate limit retries and caching happen
+/// on top of it.
+pub trait Transport: Send + Sync {
+    /// Send `request`.
+    fn execute(&self, request: Request) -> ResponseFuture<'_>;
+}
+
+impl Transport for Client {
+    fn execute(&self, request: Request) -> ResponseFuture<'_> {
+        Box::pin(Client::execute(self, request))
+    }
+}
+
+/// Shares a transport, e.g. to keep a handle on a mock to inspect the requests it got.
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn execute(&self, request: Request) -> ResponseFuture<'_> {
+        (**self).execute(request)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{ResponseFuture, Transport};
+    use reqwest::{Request, Response};
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+
+    /// Answers requests with canned responses, in order, and remembers the requests.
+    pub(crate) struct MockTransport {
+        responses: Mutex<VecDeque<http::Response<String>>>,
+        requests: Mutex<Vec<Request>>,
+    }
+
+    impl MockTransport {
+        pub(crate) fn new(responses: Vec<http::Response<String>>) -> MockTransport {
+            MockTransport {
+                responses: Mutex::new(responses.into()),
+                requests: Mutex::new(Vec::new()),
+            }
+        }
+
+        /// Answers every request with `status` and the JSON `body`.
+        pub(crate) fn json(status: u16, body: &str) -> MockTransport {
+            Self::new(vec![response(status, body)])
+        }
+
+        /// The requests sent so far.
+        pub(crate) fn requests(&self) -> std::sync::MutexGuard<'_, Vec<Request>> {
+            self.requests.lock().unwrap()
+        }
+    }
+
+    impl Transport for MockTransport {
+        fn execute(&self, request: Request) -> ResponseFuture<'_> {
+            self.requests.lock().unwrap().push(request);
+
+            // The last response answers all further requests.
+            let mut responses = self.responses.lock().unwrap();
+            let response = if responses.len() > 1 {
+                responses.pop_front().unwrap()
+            } else {
+                clone(responses.front().expect("no canned responses"))
+            };
+
+            Box::pin(async move { Ok(Response::from(response)) })
+        }
+    }
+
+    /// A response with `status` and the JSON `body`.
+    pub(crate) fn response(status: u16, body: &str) -> http::Response<String> {
+        http::Response::builder()
+            .status(status)
+            .header("content-type", "application/json")
+            .body(body.to_owned())
+            .unwrap()
+    }
+
+    fn clone(response: &http::Response<String>) -> http::Response<String> {
+        let mut builder = http::Response::builder().status(response.status());
+
+        for (name, value) in response.headers() {
+            builder = builder.header(name, value);
+        }
+
+        builder.body(response.body().clone()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_mock_transport() {
+        let transport = MockTransport::new(vec![response(200, "first"), response(404, "")]);
+        let request = |url| Request::new(reqwest::Method::GET, reqwest::Url::parse(url).unwrap());
+
+        let first = transport.execute(request("https://a.test/")).await.unwrap();
+        assert_eq!(first.text().await.unwrap(), "first");
+
+        for _ in 0..2 {
+            let other = transport.execute(request("https://b.test/")).await.unwrap();
+            assert_eq!(other.status(), 404);
+        }
+
+        assert_eq!(transport.requests().len(), 3);
+        assert_eq!(transport.requests()[0].url().as_str(), "https://a.test/");
+    }
+}
@@ -7,3 +7,71 @@ pub fn build_url(dest: &str) -> String {
 pub fn build_oauth(dest: &str) -> String {
     format!("https://oauth.reddit.com/{}/.json", dest)
 }
+
+/// Extracts the ID of a submission from a link to it, e.g.
+/// `https://www.reddit.com/r/rust/comments/abc/title/` or `https://redd.it/abc`, or from a
+/// permalink relative to reddit.com, e.g. `/r/rust/comments/abc/title/`.
+pub(crate) fn submission_id(url: &str) -> Option<&str> {
+    let url = url.trim();
+    // Permalinks are paths on reddit.com, without a host.
+    let relative = url.starts_with('/');
+    let url = url.splitn(2, "://").last()?;
+    let url = url.split(['?', '#']).next()?;
+
+    let mut parts = url.split('/').filter(|part| !part.is_empty());
+    let host = if relative {
+        "reddit.com".to_owned()
+    } else {
+        parts.next()?.to_lowercase()
+    };
+    let path: Vec<_> = parts.collect();
+
+    let id = if host == "redd.it" {
+        path.first()
+    } else if host == "reddit.com" || host.ends_with(".reddit.com") {
+        let position = path
+            .iter()
+            .position(|part| *part == "comments" || *part == "gallery")?;
+
+        path.get(position + 1)
+    } else {
+        None
+    }?;
+
+    if !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric()) {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::submission_id;
+
+    #[test]
+    fn test_submission_id() {
+        let urls = [
+            "https://www.reddit.com/r/rust/comments/jrjqy0/roux_v1/",
+            "https://old.reddit.com/r/rust/comments/jrjqy0/roux_v1/?sort=new",
+            "http://reddit.com/r/rust/comments/jrjqy0",
+            "www.reddit.com/comments/jrjqy0/",
+            "https://www.reddit.com/gallery/jrjqy0",
+            "https://redd.it/jrjqy0",
+            "https://www.reddit.com/r/rust/comments/jrjqy0/roux_v1/gbu1p6r/",
+            "/r/rust/comments/jrjqy0/roux_v1/",
+            "/r/rust/comments/jrjqy0/roux_v1/gbu1p6r/?context=3",
+            "/comments/jrjqy0",
+        ];
+
+        for url in &urls {
+            assert_eq!(submission_id(url), Some("jrjqy0"), "{}", url);
+        }
+
+        assert_eq!(submission_id("https://www.reddit.com/r/rust/"), None);
+        assert_eq!(submission_id("https://example.com/comments/jrjqy0"), None);
+        assert_eq!(submission_id("not a url"), None);
+        assert_eq!(submission_id("/r/rust/"), None);
+        assert_eq!(submission_id("/"), None);
+    }
+}
@@ -0,0 +1,150 @@
+extern crate roux;
+extern crate serde_json;
+
+/// Deserialization of responses captured from Reddit (trimmed to a few children), so a
+/// change to a response type that breaks parsing is caught without network access.
+/// Add a fixture under `tests/fixtures/` for each new response type.
+#[cfg(test)]
+mod tests {
+    use roux::live::responses::{LiveThreadAbout, LiveUpdates};
+    use roux::responses::Edited;
+    use roux::subreddit::responses::{
+        About, ModPermission, Moderators, Submissions, SubredditComments, SubredditReplies,
+        Thumbnail,
+    };
+    use roux::subreddits::responses::{Autocomplete, AutocompleteCandidate};
+    use roux::user::responses::{Trophies, UserAbout};
+    use serde::de::DeserializeOwned;
+
+    fn fixture<T: DeserializeOwned>(json: &str) -> T {
+        match serde_json::from_str(json) {
+            Ok(parsed) => parsed,
+            Err(e) => panic!("fixture doesn't deserialize: {}", e),
+        }
+    }
+
+    #[test]
+    fn test_submissions() {
+        let hot: Submissions = fixture(include_str!("fixtures/submissions.json"));
+
+        assert_eq!(hot.len(), 3);
+        assert_eq!(hot.data.after.as_deref(), Some("t3_1o4a9zq"));
+
+        let sticky = &hot.data.children[0].data;
+        assert!(sticky.stickied && sticky.is_self);
+        assert_eq!(sticky.distinguished.as_deref(), Some("moderator"));
+        assert_eq!(sticky.thumbnail, Thumbnail::SelfPost);
+        assert!(sticky.selftext_html.as_ref().unwrap().contains("<a href"));
+
+        let link = &hot.data.children[1].data;
+        assert!(!link.is_self);
+        assert_eq!(link.edited, Edited::At(1758214170.0));
+        assert_eq!(link.link_flair_text.as_deref(), Some("📡 official blog"));
+        assert!(matches!(link.thumbnail, Thumbnail::Url(_)));
+        assert_eq!(link.preview.as_ref().unwrap().images[0].source.width, 1200);
+        assert_eq!(link.extra["upvote_ratio"], 0.99);
+
+        let gallery = &hot.data.children[2].data;
+        assert!(gallery.is_deleted());
+        assert_eq!(gallery.gallery_urls().len(), 2);
+    }
+
+    #[test]
+    fn test_article_comments() {
+        let (post, comments): (Submissions, SubredditComments) =
+            fixture(include_str!("fixtures/article.json"));
+
+        assert_eq!(post.data.children[0].data.id, "1nmq0de");
+        assert_eq!(comments.len(), 3);
+
+        // Two top level comments and a reply, without the `more` stubs.
+        let flat = comments.flatten();
+        assert_eq!(flat.len(), 3);
+        assert_eq!(flat[0].author.as_deref(), Some("Kobzol"));
+        assert_eq!(flat[1].parent_id.as_deref(), Some("t1_nf4gq7z"));
+        assert!(flat[2].is_deleted());
+
+        let more = &comments.data.children[2];
+        assert_eq!(more.kind, "more");
+        assert_eq!(more.data.count, Some(92));
+
+        // A listing of replies that's only a `more` stub is turned into the stub.
+        match &flat[1].replies {
+            SubredditReplies::More(more) => assert_eq!(more.children.len(), 3),
+            _ => panic!("expected a more stub"),
+        }
+    }
+
+    #[test]
+    fn test_moderators() {
+        let moderators: Moderators = fixture(include_str!("fixtures/moderators.json"));
+        let moderators = &moderators.data.children;
+
+        assert_eq!(moderators.len(), 3);
+        assert!(moderators[0].has_permission(ModPermission::Wiki));
+        assert!(moderators[1].has_permission(ModPermission::Flair));
+        assert!(!moderators[1].has_permission(ModPermission::Access));
+        assert_eq!(moderators[2].permissions[2], ModPermission::Unknown);
+    }
+
+    #[test]
+    fn test_about() {
+        let about: About = fixture(include_str!("fixtures/about.json"));
+
+        assert_eq!(about.data.display_name, "rust");
+        assert_eq!(about.data.name, "t5_2s7lj");
+        assert_eq!(about.data.subscribers, 312846);
+        assert_eq!(about.data.over18, Some(false));
+        assert_eq!(about.data.extra["lang"], "en");
+    }
+
+    #[test]
+    fn test_user_about() {
+        let about: UserAbout = fixture(include_str!("fixtures/user_about.json"));
+
+        assert_eq!(about.data.name, "beneater");
+        assert_eq!(about.data.link_karma, 19040);
+        assert_eq!(about.data.has_verified_email, Some(true));
+    }
+
+    #[test]
+    fn test_trophies() {
+        let trophies: Trophies = fixture(include_str!("fixtures/trophies.json"));
+        let trophies = &trophies.data.trophies;
+
+        assert_eq!(trophies.len(), 2);
+        assert_eq!(trophies[0].data.granted_at, None);
+        assert_eq!(trophies[1].data.name, "15-Year Club");
+        assert_eq!(trophies[1].data.granted_at, Some(1604534400.0));
+    }
+
+    #[test]
+    fn test_live_thread() {
+        let about: LiveThreadAbout = fixture(include_str!("fixtures/live_about.json"));
+
+        assert!(about.data.is_live());
+        assert_eq!(about.data.viewer_count, Some(1834));
+        assert!(about.data.websocket_url.is_some());
+
+        let updates: LiveUpdates = fixture(include_str!("fixtures/live_updates.json"));
+
+        assert_eq!(updates.len(), 2);
+        assert_eq!(
+            updates.data.after.as_deref(),
+            Some(&*updates.data.children[1].data.name)
+        );
+        assert!(!updates.data.children[0].data.stricken);
+        assert!(updates.data.children[1].data.stricken);
+        assert_eq!(updates.data.children[1].data.author, None);
+    }
+
+    #[test]
+    fn test_autocomplete() {
+        let candidates: Autocomplete = fixture(include_str!("fixtures/autocomplete.json"));
+        let candidates = &candidates.data.children;
+
+        let names: Vec<_> = candidates.iter().map(|c| c.name()).collect();
+        assert_eq!(names, ["rust", "rustjerk", "rustlang"]);
+        assert!(matches!(candidates[2], AutocompleteCandidate::Profile(_)));
+    }
+}
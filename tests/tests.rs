@@ -7,8 +7,8 @@ extern crate tokio;
 #[cfg(test)]
 mod tests {
 
+    use roux::util::RouxError;
     use roux::Reddit;
-    use tokio;
 
     static USER_AGENT: &str = "macos:roux:v0.3.0 (by /u/beanpup_py)";
 
@@ -19,7 +19,7 @@ mod tests {
         let username = dotenv::var("USERNAME").unwrap();
         let password = dotenv::var("PASSWORD").unwrap();
 
-        let client = Reddit::new(&USER_AGENT, &client_id, &client_secret)
+        let client = Reddit::new(USER_AGENT, &client_id, &client_secret)
             .username(&username)
             .password(&password)
             .login()
@@ -30,6 +30,29 @@ mod tests {
         let me = client.unwrap();
 
         assert!(me.me().await.is_ok());
+        assert!(me.inbox().await.is_ok());
+        assert!(me.user(&username).saved().await.is_ok());
+        assert!(me.unread().await.is_ok());
+        assert!(me.subreddit("rust").hot(25, None).await.is_ok());
+        assert!(me.subreddit("rust").link_flair_templates().await.is_ok());
+        assert!(matches!(
+            me.report("t3_jrjqy0", " ").await,
+            Err(RouxError::InvalidArgument(_))
+        ));
+        // The test account doesn't moderate r/rust.
+        assert!(matches!(
+            me.lock("t3_jrjqy0").await,
+            Err(RouxError::Forbidden)
+        ));
+        assert!(matches!(
+            me.remove("t3_jrjqy0", true).await,
+            Err(RouxError::Forbidden)
+        ));
+        assert!(matches!(
+            me.subreddit("rust").modqueue(None).await,
+            Err(RouxError::Forbidden)
+        ));
+
         assert!(me.logout().await.is_ok());
     }
 }